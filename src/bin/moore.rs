@@ -66,9 +66,18 @@ fn main() {
                     "insts",
                     "func-args",
                     "call-args",
+                    "drivers",
+                    "params",
+                    "reads",
                 ])
                 .global(true),
         )
+        .arg(
+            Arg::with_name("warn-width")
+                .long("warn-width")
+                .help("Warn about assignments that implicitly extend or truncate values")
+                .global(true),
+        )
         .arg(
             Arg::with_name("inc")
                 .short("I")
@@ -184,10 +193,14 @@ fn main() {
             "insts" => Verbosity::INSTS,
             "func-args" => Verbosity::FUNC_ARGS,
             "call-args" => Verbosity::CALL_ARGS,
+            "drivers" => Verbosity::DRIVERS,
+            "params" => Verbosity::PARAMS,
+            "reads" => Verbosity::READS,
             _ => unreachable!(),
         };
     }
     session.opts.opt_level = matches.value_of("opt-level").unwrap().parse().unwrap();
+    session.opts.warn_width = matches.is_present("warn-width");
 
     // Invoke the compiler.
     score(&session, &matches);
@@ -324,6 +337,30 @@ fn score(sess: &Session, matches: &ArgMatches) {
         std::process::exit(0);
     }
 
+    // Add the built-in `std` package if there are any SystemVerilog sources,
+    // unless the user provides their own `std` package.
+    let svlog_asts = || {
+        asts.iter().flat_map(|ast| match ast {
+            score::Ast::Svlog(x) => Some(x),
+            _ => None,
+        })
+    };
+    let user_std = svlog_asts()
+        .flat_map(|x| x.items.iter())
+        .any(|item| match item.data {
+            svlog::ast::ItemData::PackageDecl(ref pkg) => pkg.name.value == Name::from("std"),
+            _ => false,
+        });
+    if svlog_asts().next().is_some() && !user_std {
+        let source = source::get_source_manager().add("<std>", svlog::STD_PACKAGE);
+        let preproc = svlog::preproc::Preprocessor::new(source, &[], &[]);
+        let lexer = svlog::lexer::Lexer::new(preproc);
+        match svlog::parser::parse(lexer, &svlog_arenas.ast) {
+            Ok(x) => asts.push(score::Ast::Svlog(x)),
+            Err(()) => std::process::exit(1),
+        }
+    }
+
     // Create the scoreboard and add the initial map of libraries.
     let arenas = score::Arenas::new();
     let sb = ScoreBoard::new(&arenas);
@@ -819,6 +856,11 @@ impl<'a, 'gcx> svlog::hir::Visitor<'gcx> for TypeVerbosityVisitor<'a, 'gcx> {
         self.print(decl.id);
         svlog::hir::walk_var_decl(self, decl);
     }
+
+    fn visit_net_decl(&mut self, decl: &'gcx svlog::hir::NetDecl) {
+        self.print(decl.id);
+        svlog::hir::walk_net_decl(self, decl);
+    }
 }
 
 impl<'a, 'gcx> TypeVerbosityVisitor<'a, 'gcx> {
@@ -2,13 +2,14 @@
 
 use crate::crate_prelude::*;
 use crate::hw::{array_type_size, is_array_type, ConstantOp};
+use std::convert::TryFrom;
 
 pub fn dialect() -> DialectHandle {
     DialectHandle::from_raw(unsafe { crate::sys::mlirGetDialectHandle__comb__() })
 }
 
 /// Predicate for a comparison operation.
-#[derive(PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CmpPred {
     Eq,
     Neq,
@@ -22,6 +23,71 @@ pub enum CmpPred {
     Uge,
 }
 
+/// The comparison predicates, indexed by their encoding in the `predicate`
+/// attribute of `comb.icmp`.
+const CMP_PREDS: [CmpPred; 10] = [
+    CmpPred::Eq,
+    CmpPred::Neq,
+    CmpPred::Slt,
+    CmpPred::Sle,
+    CmpPred::Sgt,
+    CmpPred::Sge,
+    CmpPred::Ult,
+    CmpPred::Ule,
+    CmpPred::Ugt,
+    CmpPred::Uge,
+];
+
+impl CmpPred {
+    /// Encode the predicate as an integer for the `predicate` attribute.
+    pub fn to_raw(self) -> i64 {
+        CMP_PREDS.iter().position(|&p| p == self).unwrap() as i64
+    }
+
+    /// Decode a predicate from its integer encoding in the `predicate`
+    /// attribute. Returns `None` if the encoding is invalid.
+    pub fn from_raw(raw: i64) -> Option<Self> {
+        usize::try_from(raw)
+            .ok()
+            .and_then(|index| CMP_PREDS.get(index))
+            .copied()
+    }
+
+    /// Get the mnemonic of the predicate, as used in the textual IR.
+    pub fn mnemonic(self) -> &'static str {
+        match self {
+            CmpPred::Eq => "eq",
+            CmpPred::Neq => "ne",
+            CmpPred::Slt => "slt",
+            CmpPred::Sle => "sle",
+            CmpPred::Sgt => "sgt",
+            CmpPred::Sge => "sge",
+            CmpPred::Ult => "ult",
+            CmpPred::Ule => "ule",
+            CmpPred::Ugt => "ugt",
+            CmpPred::Uge => "uge",
+        }
+    }
+}
+
+impl std::fmt::Display for CmpPred {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.mnemonic())
+    }
+}
+
+impl std::str::FromStr for CmpPred {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        CMP_PREDS
+            .iter()
+            .copied()
+            .find(|p| p.mnemonic() == s)
+            .ok_or_else(|| format!("unknown comparison predicate `{}`", s))
+    }
+}
+
 def_simple_binary_operation!(AndOp, "comb.and");
 def_simple_binary_operation!(OrOp, "comb.or");
 def_simple_binary_operation!(XorOp, "comb.xor");
@@ -39,6 +105,36 @@ def_operation_single_result!(ICmpOp, "comb.icmp");
 def_operation_single_result!(MuxOp, "comb.mux");
 def_operation_single_result!(ExtractOp, "comb.extract");
 def_operation_single_result!(ConcatOp, "comb.concat");
+def_operation_single_result!(ReplicateOp, "comb.replicate");
+def_operation_single_result!(ParityOp, "comb.parity");
+
+/// Add a variadic constructor to operations that accept any number of
+/// operands of the same type.
+macro_rules! impl_variadic_operation {
+    ($($name:ident),*) => {$(
+        impl $name {
+            /// Create a new operation over an arbitrary number of operands.
+            ///
+            /// The result has the width of the first operand. Returns `None` if
+            /// no operands are given.
+            pub fn with_operands(
+                builder: &mut Builder,
+                values: impl IntoIterator<Item = Value>,
+            ) -> Option<Self> {
+                let mut values = values.into_iter().peekable();
+                let width = integer_type_width(values.peek()?.ty());
+                Some(builder.build_with(|builder, state| {
+                    for value in values {
+                        state.add_operand(value);
+                    }
+                    state.add_result(get_integer_type(builder.cx, width));
+                }))
+            }
+        }
+    )*};
+}
+
+impl_variadic_operation!(AndOp, OrOp, XorOp, AddOp, MulOp);
 
 impl ICmpOp {
     /// Create a new comparison operation.
@@ -47,30 +143,45 @@ impl ICmpOp {
             state.add_operand(lhs);
             state.add_operand(rhs);
             let attr_ty = get_integer_type(builder.cx, 64);
-            let attr = get_integer_attr(
-                attr_ty,
-                match pred {
-                    CmpPred::Eq => 0,
-                    CmpPred::Neq => 1,
-                    CmpPred::Slt => 2,
-                    CmpPred::Sle => 3,
-                    CmpPred::Sgt => 4,
-                    CmpPred::Sge => 5,
-                    CmpPred::Ult => 6,
-                    CmpPred::Ule => 7,
-                    CmpPred::Ugt => 8,
-                    CmpPred::Uge => 9,
-                },
-            );
+            let attr = get_integer_attr(attr_ty, pred.to_raw());
             state.add_attribute("predicate", attr);
             state.add_result(get_integer_type(builder.cx, 1));
         })
     }
+
+    /// Get the predicate of the comparison.
+    pub fn predicate(&self) -> CmpPred {
+        let raw = self.attr_i64("predicate");
+        CmpPred::from_raw(raw).unwrap_or_else(|| panic!("invalid `comb.icmp` predicate {}", raw))
+    }
 }
 
 impl MuxOp {
     /// Create a new mux operation.
+    ///
+    /// Panics if the condition is not an `i1`, or if the two values differ in
+    /// type.
     pub fn new(builder: &mut Builder, cond: Value, true_value: Value, false_value: Value) -> Self {
+        assert!(
+            is_integer_type(cond.ty()) && integer_type_width(cond.ty()) == 1,
+            "`comb.mux` condition must be i1, got {}",
+            cond.ty()
+        );
+        let (true_ty, false_ty) = (true_value.ty(), false_value.ty());
+        if is_integer_type(true_ty) && is_integer_type(false_ty) {
+            assert_eq!(
+                integer_type_width(true_ty),
+                integer_type_width(false_ty),
+                "`comb.mux` operands must have the same width"
+            );
+        } else {
+            assert!(
+                true_ty == false_ty,
+                "`comb.mux` operands must have the same type, got {} and {}",
+                true_ty,
+                false_ty
+            );
+        }
         builder.build_with(|_, state| {
             state.add_operand(cond);
             state.add_operand(true_value);
@@ -91,20 +202,59 @@ impl ExtractOp {
         })
     }
 
+    /// Extract `length` bits starting at bit `offset` from an integer.
+    ///
+    /// Panics if the extracted range does not lie within the integer.
     pub fn with_sizes(builder: &mut Builder, value: Value, offset: usize, length: usize) -> Self {
+        let width = integer_type_width(value.ty());
+        assert!(
+            offset + length <= width,
+            "`comb.extract` of bits {}..{} out of bounds for {}",
+            offset,
+            offset + length,
+            value.ty()
+        );
         Self::new(builder, get_integer_type(builder.cx, length), value, offset)
     }
 }
 
-impl ConcatOp {
-    pub fn new(builder: &mut Builder, values: impl IntoIterator<Item = Value>) -> Self {
+impl ParityOp {
+    /// Compute the XOR of all bits of an integer.
+    pub fn new(builder: &mut Builder, value: Value) -> Self {
         builder.build_with(|builder, state| {
+            state.add_operand(value);
+            state.add_result(get_integer_type(builder.cx, 1));
+        })
+    }
+}
+
+impl ConcatOp {
+    /// Concatenate a list of integers, with the first value becoming the most
+    /// significant bits of the result.
+    ///
+    /// Returns `None` if no values are given, since a zero-width concatenation
+    /// is not meaningful.
+    pub fn new(builder: &mut Builder, values: impl IntoIterator<Item = Value>) -> Option<Self> {
+        let mut values = values.into_iter().peekable();
+        values.peek()?;
+        Some(builder.build_with(|builder, state| {
             let mut width = 0;
             for value in values {
                 state.add_operand(value);
                 width += integer_type_width(value.ty());
             }
             state.add_result(get_integer_type(builder.cx, width));
+        }))
+    }
+}
+
+impl ReplicateOp {
+    /// Concatenate an integer with itself a number of times.
+    pub fn new(builder: &mut Builder, value: Value, times: usize) -> Self {
+        builder.build_with(|builder, state| {
+            state.add_operand(value);
+            let width = integer_type_width(value.ty()) * times;
+            state.add_result(get_integer_type(builder.cx, width));
         })
     }
 }
@@ -160,8 +310,44 @@ pub(crate) fn trunc_or_zext(builder: &mut Builder, index: Value, into_ty: Type)
         ExtractOp::with_sizes(builder, index, 0, target_width).into()
     } else if target_width > actual_width {
         let zero = ConstantOp::new(builder, target_width - actual_width, &BigInt::zero()).into();
-        ConcatOp::new(builder, [zero, index].iter().copied()).into()
+        ConcatOp::new(builder, [zero, index].iter().copied())
+            .unwrap()
+            .into()
     } else {
         index
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cmp_pred_mnemonics() {
+        for &pred in &CMP_PREDS {
+            assert_eq!(pred.to_string().parse::<CmpPred>(), Ok(pred));
+        }
+        assert_eq!(CmpPred::Neq.to_string(), "ne");
+        assert!("foo".parse::<CmpPred>().is_err());
+    }
+
+    #[test]
+    fn concat_without_operands() {
+        let cx = OwnedContext::new();
+        cx.load_dialect(dialect());
+        let mut builder = Builder::new(*cx);
+        assert!(ConcatOp::new(&mut builder, Vec::<Value>::new()).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn extract_past_end() {
+        let cx = OwnedContext::new();
+        cx.load_dialect(dialect());
+        cx.load_dialect(crate::hw::dialect());
+        let mut builder = Builder::new(*cx);
+        builder.set_insertion_point_to_end(unsafe { mlirBlockCreate(0, [].as_ptr(), [].as_ptr()) });
+        let value = ConstantOp::new(&mut builder, 4, &BigInt::zero()).into();
+        ExtractOp::with_sizes(&mut builder, value, 2, 4);
+    }
+}
@@ -75,6 +75,8 @@ pub struct SessionOptions {
     pub verbosity: Verbosity,
     /// The optimization level.
     pub opt_level: usize,
+    /// Warn about assignments that implicitly extend or truncate a value.
+    pub warn_width: bool,
 }
 
 bitflags! {
@@ -94,5 +96,8 @@ bitflags! {
         const INSTS         = 1 << 8;
         const FUNC_ARGS     = 1 << 9;
         const CALL_ARGS     = 1 << 10;
+        const DRIVERS       = 1 << 11;
+        const PARAMS        = 1 << 12;
+        const READS         = 1 << 13;
     }
 }
@@ -25,6 +25,18 @@ pub type HybridValue = (llhd::ir::Value, mlir::Value);
 pub type HybridType = (llhd::Type, mlir::Type);
 pub type HybridBlock = (llhd::ir::Block, mlir::Block);
 
+/// The maximum depth of the instance hierarchy.
+///
+/// Recursive instantiations that do not reach a base case are aborted once
+/// they exceed this depth.
+const MAX_INSTANCE_DEPTH: usize = 256;
+
+/// The maximum number of iterations of a generate-for loop.
+///
+/// Loops whose condition never becomes false, for example because the genvar
+/// steps away from the bound, are aborted once they exceed this count.
+const MAX_GENERATE_ITERATIONS: usize = 1 << 20;
+
 /// A code generator.
 ///
 /// Use this struct to emit LLHD code for nodes in a [`Context`].
@@ -65,6 +77,8 @@ struct Tables<'gcx> {
     module_signatures: HashMap<NodeEnvId, (llhd::ir::UnitName, llhd::ir::Signature)>,
     interned_types: HashMap<&'gcx UnpackedType<'gcx>, Result<HybridType>>,
     function_defs: HashMap<NodeEnvId, Result<Rc<EmittedFunction>>>,
+    /// The modules currently being emitted, from the root down.
+    module_stack: Vec<NodeEnvId>,
 }
 
 impl<'gcx, C> Deref for CodeGenerator<'gcx, C> {
@@ -110,11 +124,67 @@ impl<'a, 'gcx, C: Context<'gcx>> CodeGenerator<'gcx, &'a C> {
             HirNode::Module(m) => m,
             _ => panic!("expected {:?} to be a module", id),
         };
+
+        // Guard against recursive instantiations that never reach a base
+        // case.
+        if self.tables.module_stack.contains(&id.env(env)) {
+            self.emit(
+                DiagBuilder2::error(format!(
+                    "module `{}` instantiates itself with identical parameters",
+                    hir.name
+                ))
+                .span(hir.name.span)
+                .add_note(
+                    "Recursive instantiations must change a parameter such that a generate \
+                     block eventually stops the recursion",
+                ),
+            );
+            return Err(());
+        }
+        if self.tables.module_stack.len() >= MAX_INSTANCE_DEPTH {
+            self.emit(
+                DiagBuilder2::error(format!(
+                    "instantiation of module `{}` exceeds the maximum hierarchy depth of {}",
+                    hir.name, MAX_INSTANCE_DEPTH
+                ))
+                .span(hir.name.span)
+                .add_note("The recursive instantiation may be missing a base case"),
+            );
+            return Err(());
+        }
+        self.tables.module_stack.push(id.env(env));
+        let result = self.emit_module_uncached(id, env, hir);
+        self.tables.module_stack.pop();
+        result
+    }
+
+    /// Emit a module with a parameter environment, bypassing the cache.
+    fn emit_module_uncached(
+        &mut self,
+        id: NodeId,
+        env: ParamEnv,
+        hir: &'gcx hir::Module<'gcx>,
+    ) -> Result<Rc<EmittedModule<'gcx>>> {
         info!("Emit module `{}` with {:?}", hir.name, env);
 
         // Emit detailed port information if requested.
         if self.sess().has_verbosity(Verbosity::PORTS) {
-            emit_port_details(self.cx, hir, env);
+            emit_port_details(self.cx, id, hir, env);
+        }
+
+        // Emit the drivers of each signal if requested.
+        if self.sess().has_verbosity(Verbosity::DRIVERS) {
+            emit_driver_details(self.cx, id, hir, env)?;
+        }
+
+        // Emit the signals read by each continuous assignment if requested.
+        if self.sess().has_verbosity(Verbosity::READS) {
+            emit_read_details(self.cx, hir, env)?;
+        }
+
+        // Emit the parameters of the module if requested.
+        if self.sess().has_verbosity(Verbosity::PARAMS) {
+            emit_param_details(self.cx, id, hir)?;
         }
 
         // Determine entity type and port names.
@@ -177,7 +247,7 @@ impl<'a, 'gcx, C: Context<'gcx>> CodeGenerator<'gcx, &'a C> {
         }
 
         // Emit the actual contents of the entity.
-        gen.emit_module_block(id, env, &hir.block, &entity_name)?;
+        gen.emit_module_block(id, env, &hir.block, &entity_name, "", None)?;
 
         // Assign default values to undriven output ports.
         for port in ports.outputs.iter() {
@@ -305,7 +375,22 @@ impl<'a, 'gcx, C: Context<'gcx>> CodeGenerator<'gcx, &'a C> {
                         sig.add_input(llty);
                         inputs.push(mp);
                     }
-                    ast::PortDir::Inout | ast::PortDir::Output => {
+                    ast::PortDir::Output => {
+                        sig.add_output(llty);
+                        outputs.push(mp);
+                    }
+                    ast::PortDir::Inout => {
+                        self.emit(
+                            DiagBuilder2::warning(format!(
+                                "unsupported: bidirectional port `{}`; lowered as output",
+                                port.name
+                            ))
+                            .span(port.span)
+                            .add_note(
+                                "Multiple drivers on an `inout` port are not resolved; the \
+                                 port can be driven from within the module only",
+                            ),
+                        );
                         sig.add_output(llty);
                         outputs.push(mp);
                     }
@@ -338,6 +423,7 @@ impl<'a, 'gcx, C: Context<'gcx>> CodeGenerator<'gcx, &'a C> {
             .chain(intf_hir.block.decls.iter().map(|&id| {
                 Ok(match self.hir_of(id)? {
                     HirNode::VarDecl(x) => (id, x.name, x.init),
+                    HirNode::NetDecl(x) => (id, x.name, x.init),
                     _ => unreachable!(),
                 })
             }));
@@ -370,6 +456,17 @@ impl<'a, 'gcx, C: Context<'gcx>> CodeGenerator<'gcx, &'a C> {
             _ => unreachable!(),
         };
 
+        // Warn about signals that are not assigned on all paths through an
+        // `always_comb` procedure.
+        if hir.kind == ast::ProcedureKind::AlwaysComb {
+            for latch in self.inferred_latches(id, env)? {
+                self.warn_inferred_latch(id, latch)?;
+            }
+        }
+
+        // Warn about statements that can never execute.
+        self.check_unreachable_stmts(id);
+
         // Find the accessed nodes.
         let acc = self.accessed_nodes(hir.stmt, env)?;
         trace!("Process accesses {:#?}", acc);
@@ -388,6 +485,9 @@ impl<'a, 'gcx, C: Context<'gcx>> CodeGenerator<'gcx, &'a C> {
                     sig_ty.dims.extend(&intf_ty.dims);
                     sig_ty.intern(self.cx)
                 }
+                AccessedNode::Gen(gen, index, id) => {
+                    self.type_of(id, self.genblk_iteration_env(gen, index, env)?)?
+                }
             })?;
             sig.add_input(llhd::signal_ty(ty.0));
             mlir_inputs.push(ty.1);
@@ -403,6 +503,9 @@ impl<'a, 'gcx, C: Context<'gcx>> CodeGenerator<'gcx, &'a C> {
                     sig_ty.dims.extend(&intf_ty.dims);
                     sig_ty.intern(self.cx)
                 }
+                AccessedNode::Gen(gen, index, id) => {
+                    self.type_of(id, self.genblk_iteration_env(gen, index, env)?)?
+                }
             })?;
             sig.add_output(llhd::signal_ty(ty.0));
             mlir_outputs.push(ty.1);
@@ -440,7 +543,7 @@ impl<'a, 'gcx, C: Context<'gcx>> CodeGenerator<'gcx, &'a C> {
         // Assign names to inputs and outputs.
         let guess_name = |id| {
             let (prefix, id) = match id {
-                AccessedNode::Regular(id) => (None, id),
+                AccessedNode::Regular(id) | AccessedNode::Gen(_, _, id) => (None, id),
                 AccessedNode::Intf(inst_id, id) => {
                     let inst_name = match self.hir_of(inst_id).ok()? {
                         HirNode::IntPort(x) => Some(x.name),
@@ -452,6 +555,7 @@ impl<'a, 'gcx, C: Context<'gcx>> CodeGenerator<'gcx, &'a C> {
             };
             let name = match self.hir_of(id).ok()? {
                 HirNode::VarDecl(x) => Some(x.name),
+                HirNode::NetDecl(x) => Some(x.name),
                 HirNode::IntPort(x) => Some(x.name),
                 _ => None,
             };
@@ -576,18 +680,22 @@ impl<'a, 'gcx, C: Context<'gcx>> CodeGenerator<'gcx, &'a C> {
         // Emit the main statement.
         pg.emit_stmt(hir.stmt, env)?;
 
-        // Emit epilogue.
-        match hir.kind {
-            ast::ProcedureKind::Initial | ast::ProcedureKind::Final => {
+        // Emit epilogue. Procedures that execute only once halt at the end,
+        // all others jump back to their head.
+        assert_eq!(
+            head_blk.is_some(),
+            hir.kind.is_repeating(),
+            "{:?} procedure has inconsistent head block",
+            hir.kind
+        );
+        match head_blk {
+            None => {
                 pg.builder.ins().halt();
                 circt::llhd::HaltOp::new(pg.mlir_builder);
             }
-            ast::ProcedureKind::Always
-            | ast::ProcedureKind::AlwaysComb
-            | ast::ProcedureKind::AlwaysLatch
-            | ast::ProcedureKind::AlwaysFf => {
-                pg.builder.ins().br(head_blk.unwrap().0);
-                circt::cf::BranchOp::new(pg.mlir_builder, head_blk.unwrap().1);
+            Some((blk, mlir_blk)) => {
+                pg.builder.ins().br(blk);
+                circt::cf::BranchOp::new(pg.mlir_builder, mlir_blk);
             }
         }
 
@@ -703,6 +811,19 @@ impl<'a, 'gcx, C: Context<'gcx>> CodeGenerator<'gcx, &'a C> {
         }
     }
 
+    /// Determine the genvar initialized by the initialization step of a
+    /// generate loop.
+    fn genvar_of_init(&mut self, id: NodeId, env: ParamEnv) -> Result<NodeId> {
+        match self.hir_of(id)? {
+            HirNode::GenvarDecl(_) => Ok(id),
+            HirNode::Stmt(stmt) => match stmt.kind {
+                hir::StmtKind::Assign { lhs, .. } => self.resolve_node(lhs, env),
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        }
+    }
+
     /// Execute the iteration step of a generate loop.
     fn execute_genvar_step(&mut self, id: NodeId, env: ParamEnv) -> Result<ParamEnv> {
         let hir = self.hir_of(id)?;
@@ -774,6 +895,9 @@ impl<'a, 'gcx, C: Context<'gcx>> CodeGenerator<'gcx, &'a C> {
         let return_ty = typeck::return_type_of_function(self.cx, &ast.prototype, env);
         let lowered_return_ty = self.emit_type_both(return_ty)?;
 
+        // Warn about statements that can never execute.
+        self.check_unreachable_stmts(id);
+
         // Create the function signature and function builder.
         let mut sig = llhd::ir::Signature::new();
         sig.set_return_type(lowered_return_ty.0.clone());
@@ -934,6 +1058,9 @@ struct UnitGenerator<'a, 'gcx, C> {
     /// A stack of blocks, the last of which will be branched to by a `break`
     /// statement.
     break_stack: Vec<HybridBlock>,
+    /// A stack of enclosing named blocks, together with the block that a
+    /// `disable` of the name branches to. The latter is created on first use.
+    disable_stack: Vec<(Name, Option<HybridBlock>)>,
 }
 
 impl<'a, 'gcx, C> Deref for UnitGenerator<'a, 'gcx, C> {
@@ -970,6 +1097,7 @@ impl<'a, 'gcx, C> UnitGenerator<'a, 'gcx, C> {
             terminated: false,
             break_stack: Default::default(),
             continue_stack: Default::default(),
+            disable_stack: Default::default(),
         }
     }
 }
@@ -983,10 +1111,7 @@ where
         match self.values.get(&src) {
             Some(&v) => v,
             None => bug_span!(
-                self.span(match src {
-                    AccessedNode::Regular(id) => id,
-                    AccessedNode::Intf(_, id) => id,
-                }),
+                self.span(src.id()),
                 self.cx,
                 "no value emitted for {:?}",
                 src
@@ -999,6 +1124,42 @@ where
         self.values.insert(src, value);
     }
 
+    /// Get the emitted value for a declaration in one iteration of a generate
+    /// loop.
+    ///
+    /// Emits an error if no such iteration has been emitted.
+    fn emitted_genblk_value(&self, src: AccessedNode, span: Span) -> Result<HybridValue> {
+        if let Some(&v) = self.values.get(&src) {
+            return Ok(v);
+        }
+        let (gen, index) = match src {
+            AccessedNode::Gen(gen, index, _) => (gen, index),
+            _ => unreachable!(),
+        };
+        let label = match self.hir_of(gen)? {
+            HirNode::Gen(hir::Gen {
+                kind:
+                    hir::GenKind::For {
+                        label: Some(label), ..
+                    },
+                ..
+            }) => label.value,
+            _ => unreachable!(),
+        };
+        self.emit(
+            DiagBuilder2::error(format!(
+                "generate block `{}[{}]` has not been elaborated",
+                label, index
+            ))
+            .span(span)
+            .add_note(
+                "A generate block can only be referenced after the generate loop that creates it, \
+                 and the index must match one of the loop's iterations.",
+            ),
+        );
+        Err(())
+    }
+
     /// Clear the cached MIR lvalues and rvalues. This should be called before
     /// or after emitting an expression, and at least for every statement.
     /// Otherwise MIR codegen might reuse values that have become out-of-date
@@ -1009,23 +1170,38 @@ where
     }
 
     /// Emit the code for the contents of a module.
+    ///
+    /// The `scope` is the hierarchical path of the generate block being
+    /// emitted (e.g. `g[1].`), and is prepended to declaration and instance
+    /// names such that different generate iterations receive distinct names. If
+    /// the block is the body of a generate loop, `iteration` identifies the
+    /// loop and the genvar value, such that the block's declarations can be
+    /// accessed through hierarchical references such as `g[1].x`.
     fn emit_module_block(
         &mut self,
         id: NodeId,
         env: ParamEnv,
         hir: &hir::ModuleBlock,
         name_prefix: &str,
+        scope: &str,
+        iteration: Option<(NodeId, isize)>,
     ) -> Result<()> {
         // Emit declarations.
         for &decl_id in &hir.decls {
-            let hir = match self.hir_of(decl_id)? {
-                HirNode::VarDecl(x) => x,
+            let (name, init) = match self.hir_of(decl_id)? {
+                HirNode::VarDecl(x) => (x.name, x.init),
+                HirNode::NetDecl(x) => (x.name, x.init),
                 _ => unreachable!(),
             };
             let ty = self.type_of(decl_id, env)?;
-            let value = self.emit_varnet_decl(decl_id, ty, env, hir.init)?;
-            self.builder.set_name(value.0, hir.name.value.into());
+            let value = self.emit_varnet_decl(decl_id, ty, env, init, scope)?;
+            self.builder
+                .set_name(value.0, format!("{}{}", scope, name.value));
             self.values.insert(decl_id.into(), value);
+            if let Some((gen, index)) = iteration {
+                self.values
+                    .insert(AccessedNode::Gen(gen, index, decl_id), value);
+            }
         }
 
         // Emit interface instances.
@@ -1054,10 +1230,17 @@ where
             let signals = self.determine_interface_signals(intf_ty, &inst_ty.dims)?;
             let mut signal_lookup = HashMap::new();
             for signal in signals {
-                let value =
-                    self.emit_varnet_decl(signal.decl_id, signal.ty, intf_ty.env, signal.default)?;
-                self.builder
-                    .set_name(value.0, format!("{}.{}", inst.hir.name, signal.name));
+                let value = self.emit_varnet_decl(
+                    signal.decl_id,
+                    signal.ty,
+                    intf_ty.env,
+                    signal.default,
+                    scope,
+                )?;
+                self.builder.set_name(
+                    value.0,
+                    format!("{}{}.{}", scope, inst.hir.name, signal.name),
+                );
                 let src = AccessedNode::Intf(inst_id, signal.decl_id);
                 trace!(
                     "Emitted value for {:?} {}.{}",
@@ -1077,6 +1260,7 @@ where
                 inst.as_ref(),
                 &ports.inputs,
                 &ports.outputs,
+                None,
             )?;
             trace!("Attaching interface inputs {:?}", inputs);
             trace!("Attaching interface outputs {:?}", outputs);
@@ -1106,6 +1290,7 @@ where
             };
 
             // Map the assignment to an MIR node.
+            hir::analysis::check_enum_assign(self.cx, hir.lhs, hir.rhs, env)?;
             let assign_mir = self.mir_assignment_from_concurrent(Ref(hir), env);
             debug!("Concurrent assignment: {:#?}", assign_mir);
 
@@ -1122,8 +1307,12 @@ where
                 }
             }
 
-            // Emit the assignments.
-            let delay = self.mk_const_time(&num::zero(), 0, 1);
+            // Emit the assignments. The delay may depend on genvars, so it is
+            // evaluated in the assignment's environment.
+            let delay = match hir.delay {
+                Some(delay) => self.emit_rvalue(delay, env)?,
+                None => self.mk_const_time(&num::zero(), 0, 1),
+            };
             for &assign in &simplified {
                 let lhs = self.emit_mir_lvalue(assign.lhs)?;
                 let rhs = self.emit_mir_rvalue(assign.rhs)?;
@@ -1143,46 +1332,66 @@ where
                 InstTarget::Module(x) => self.hir_of_module(x)?,
                 _ => continue,
             };
+            hir::analysis::check_port_widths(self.cx, &inst);
 
             // Emit the instantiated module.
             let target = self.emit_module_with_env(target_module.id, inst.inner_env)?;
 
-            // Prepare the port assignments.
-            let (inputs, outputs) = self.emit_port_connections(
-                target_module.ports_new,
-                inst.as_ref(),
-                &target.ports.inputs,
-                &target.ports.outputs,
-            )?;
+            // Determine the individual elements of an instance array. Each
+            // element is identified by its position counted from the right
+            // bound, which selects the slice of the connections it receives,
+            // and by its index, which names it.
+            let elements = match self.instance_array_indices(&inst, env)? {
+                Some(indices) => {
+                    let size = indices.len();
+                    indices
+                        .into_iter()
+                        .enumerate()
+                        .map(|(pos, index)| {
+                            (
+                                Some((pos, size, index)),
+                                format!("{}{}[{}]", scope, inst.hir.name.value, index),
+                            )
+                        })
+                        .collect()
+                }
+                None => vec![(None, format!("{}{}", scope, inst.hir.name.value))],
+            };
 
-            // Instantiate the module.
-            let ext_unit = self.builder.add_extern(
-                self.into.unit(target.unit).name().clone(),
-                self.into.unit(target.unit).sig().clone(),
-            );
-            if !inst.hir.ast.dims.is_empty() {
-                bug_span!(
-                    inst.hir.ast.span(),
-                    self.cx,
-                    "instance arrays of modules not supported"
+            for (slice, name) in elements {
+                // Prepare the port assignments.
+                let (inputs, outputs) = self.emit_port_connections(
+                    target_module.ports_new,
+                    inst.as_ref(),
+                    &target.ports.inputs,
+                    &target.ports.outputs,
+                    slice,
+                )?;
+
+                // Instantiate the module.
+                let ext_unit = self.builder.add_extern(
+                    self.into.unit(target.unit).name().clone(),
+                    self.into.unit(target.unit).sig().clone(),
+                );
+                self.builder.ins().inst(
+                    ext_unit,
+                    inputs.iter().map(|x| x.0).collect(),
+                    outputs.iter().map(|x| x.0).collect(),
+                );
+                circt::llhd::InstanceOp::new(
+                    self.mlir_builder,
+                    &self.unique_names.add(&name),
+                    &target.mlir_symbol,
+                    inputs.iter().map(|x| x.1),
+                    outputs.iter().map(|x| x.1),
                 );
             }
-            self.builder.ins().inst(
-                ext_unit,
-                inputs.iter().map(|x| x.0).collect(),
-                outputs.iter().map(|x| x.0).collect(),
-            );
-            circt::llhd::InstanceOp::new(
-                self.mlir_builder,
-                &self.unique_names.add(&inst.hir.name.value.to_string()),
-                &target.mlir_symbol,
-                inputs.iter().map(|x| x.1),
-                outputs.iter().map(|x| x.1),
-            );
         }
 
-        // Emit generate blocks.
-        for &gen_id in &hir.gens {
+        // Emit generate blocks. Generate constructs are numbered from 1 in the
+        // order they appear in the scope, which names unlabeled blocks
+        // `genblk<n>` (IEEE 1800-2017 section 27.6).
+        for (gen_index, &gen_id) in hir.gens.iter().enumerate() {
             let hir = match self.hir_of(gen_id)? {
                 HirNode::Gen(x) => x,
                 _ => unreachable!(),
@@ -1197,10 +1406,10 @@ where
                     let k = self.constant_value_of(cond, env);
                     if k.is_false() {
                         if let Some(else_body) = else_body {
-                            self.emit_module_block(id, env, else_body, name_prefix)?;
+                            self.emit_module_block(id, env, else_body, name_prefix, scope, None)?;
                         }
                     } else {
-                        self.emit_module_block(id, env, main_body, name_prefix)?;
+                        self.emit_module_block(id, env, main_body, name_prefix, scope, None)?;
                     }
                 }
                 hir::GenKind::For {
@@ -1208,16 +1417,78 @@ where
                     cond,
                     step,
                     ref body,
+                    label,
                 } => {
+                    let label = match label {
+                        Some(label) => label.value.to_string(),
+                        None => format!("genblk{}", gen_index + 1),
+                    };
                     let mut local_env = env;
                     for &i in init {
                         local_env = self.execute_genvar_init(i, local_env)?;
                     }
+                    let genvar = self.genvar_of_init(*init.last().unwrap(), local_env)?;
+                    let mut iterations = 0;
                     while self.constant_value_of(cond, local_env).is_true() {
-                        self.emit_module_block(id, local_env, body, name_prefix)?;
+                        iterations += 1;
+                        if iterations > MAX_GENERATE_ITERATIONS {
+                            self.emit(
+                                DiagBuilder2::error(format!(
+                                    "generate loop exceeds {} iterations",
+                                    MAX_GENERATE_ITERATIONS
+                                ))
+                                .span(hir.span)
+                                .add_note(
+                                    "Check that the loop condition eventually becomes false \
+                                     for the genvar's step",
+                                ),
+                            );
+                            return Err(());
+                        }
+                        let index = self.constant_value_of(genvar, local_env);
+                        let local_scope = format!("{}{}[{}].", scope, label, index.kind);
+                        let iteration = index
+                            .get_int()
+                            .and_then(|i| i.to_isize())
+                            .map(|i| (gen_id, i));
+                        self.emit_module_block(
+                            id,
+                            local_env,
+                            body,
+                            name_prefix,
+                            &local_scope,
+                            iteration,
+                        )?;
                         local_env = self.execute_genvar_step(step, local_env)?;
                     }
                 }
+                hir::GenKind::Case {
+                    expr,
+                    ref ways,
+                    ref default,
+                } => {
+                    let selector = self.constant_value_of(expr, env);
+                    let taken = ways
+                        .iter()
+                        .find(|(exprs, _)| {
+                            exprs.iter().any(|&expr| {
+                                let label = self.constant_value_of(expr, env);
+                                match (&selector.kind, &label.kind) {
+                                    (ValueKind::Int(a, ..), ValueKind::Int(b, ..)) => a == b,
+                                    _ => false,
+                                }
+                            })
+                        })
+                        .map(|(_, body)| body)
+                        .or(default.as_ref());
+                    if let Some(body) = taken {
+                        self.emit_module_block(id, env, body, name_prefix, scope, None)?;
+                    }
+                }
+                hir::GenKind::Block { ref body, label } => {
+                    let local_scope = format!("{}{}.", scope, label.value);
+                    self.emit_module_block(id, env, body, name_prefix, &local_scope, None)?;
+                }
                 _ => return self.unimp_msg("code generation for", hir),
             }
         }
@@ -1225,6 +1496,11 @@ where
         // Emit and instantiate procedures.
         for &proc_id in &hir.procs {
             let prok = self.emit_procedure(proc_id, env, name_prefix)?;
+            for &id in prok.inputs.iter().chain(prok.outputs.iter()) {
+                if let AccessedNode::Gen(..) = id {
+                    self.emitted_genblk_value(id, self.span(proc_id))?;
+                }
+            }
             let lookup_value = |&id: &AccessedNode| match self.values.get(&id) {
                 Some(v) => v.clone(),
                 None => {
@@ -1262,13 +1538,131 @@ where
         Ok(())
     }
 
+    /// Determine the indices of the elements of an instance array.
+    ///
+    /// Returns `None` if the instantiation is not an array. The indices are
+    /// ordered from the right bound to the left bound of the array, such that
+    /// the first element receives the least significant bits of a connection.
+    fn instance_array_indices(
+        &mut self,
+        inst: &InstDetails<'gcx>,
+        env: ParamEnv,
+    ) -> Result<Option<Vec<isize>>> {
+        let ty = self.type_of_inst(Ref(inst.hir), env);
+        if ty.is_error() {
+            return Err(());
+        }
+        let range = match ty.dims.as_slice() {
+            [] => return Ok(None),
+            [ty::UnpackedDim::Range(r)] => *r,
+            [ty::UnpackedDim::Array(n)] => ty::Range {
+                size: *n,
+                dir: ty::RangeDir::Up,
+                offset: 0,
+            },
+            [_] => {
+                self.emit(
+                    DiagBuilder2::error(format!(
+                        "instance array `{}` must have a fixed size",
+                        inst.hir.name
+                    ))
+                    .span(inst.hir.ast.span()),
+                );
+                return Err(());
+            }
+            _ => {
+                self.emit(
+                    DiagBuilder2::error("unsupported: multi-dimensional instance arrays")
+                        .span(inst.hir.ast.span()),
+                );
+                return Err(());
+            }
+        };
+        Ok(Some(
+            (0..range.size as isize)
+                .map(|k| range.right() - k * range.increment())
+                .collect(),
+        ))
+    }
+
+    /// Determine which part of a connection is assigned to a port of an
+    /// instance array element.
+    ///
+    /// Returns the type, base, and length of the slice, or `None` if the
+    /// connection is to be used as is.
+    fn slice_of_connection(
+        &mut self,
+        port: &ModulePort<'gcx>,
+        conn_ty: &'gcx UnpackedType<'gcx>,
+        span: Span,
+        slice: Option<(usize, usize, isize)>,
+    ) -> Result<Option<(&'gcx UnpackedType<'gcx>, usize, usize)>> {
+        let (pos, size, _) = match slice {
+            Some(x) => x,
+            None => return Ok(None),
+        };
+        let port_width = port.ty.get_bit_size();
+        let conn_width = conn_ty.get_bit_size();
+        match (port_width, conn_width) {
+            (Some(p), Some(c)) if p == c => Ok(None),
+            (Some(p), Some(c)) if p * size == c => {
+                let sbvt = conn_ty.simple_bit_vector(self.cx, span).change_size(p);
+                Ok(Some((sbvt.to_unpacked(self.cx), pos * p, p)))
+            }
+            _ => {
+                self.emit(
+                    DiagBuilder2::error(format!(
+                        "connection of type `{}` does not fit port `{}` of type `{}` in an \
+                         array of {} instances",
+                        conn_ty, port.name, port.ty, size
+                    ))
+                    .span(span)
+                    .add_note(format!(
+                        "Connection must either be as wide as the port, or {} times as wide",
+                        size
+                    )),
+                );
+                Err(())
+            }
+        }
+    }
+
+    /// Create a MIR constant of type `bit [31:0]`.
+    fn mk_mir_const_u32(
+        &self,
+        value: usize,
+        origin: NodeId,
+        env: ParamEnv,
+        span: Span,
+    ) -> &'gcx mir::Rvalue<'gcx> {
+        let ty =
+            ty::SbvType::new(ty::Domain::TwoValued, ty::Sign::Unsigned, 32).to_unpacked(self.cx);
+        self.arena().alloc_mir_rvalue(mir::Rvalue {
+            id: NodeId::alloc(),
+            origin,
+            env,
+            span,
+            ty,
+            kind: mir::RvalueKind::Const(self.intern_value(value::make_int(ty, value.into()))),
+            konst: true,
+        })
+    }
+
     /// Emit code for the connections made in a port list.
+    ///
+    /// For elements of an instance array, `slice` carries the element's
+    /// position, the size of the array, and the element's index. Connections
+    /// whose width equals the port width times the array size are sliced such
+    /// that each element receives its own part; connections of the port's
+    /// width are broadcast to all elements. The sliced connections are named
+    /// after the array element and the port, e.g. `u[3].d`.
     fn emit_port_connections(
         &mut self,
         port_list: &PortList<'gcx>,
         inst: &InstDetails<'gcx>,
         inputs: &[ModulePort<'gcx>],
         outputs: &[ModulePort<'gcx>],
+        slice: Option<(usize, usize, isize)>,
     ) -> Result<(Vec<HybridValue>, Vec<HybridValue>)> {
         // Map the values associated with the external ports to internal
         // ports.
@@ -1330,6 +1724,25 @@ where
                     if mir.is_error() {
                         return Err(());
                     }
+                    let part = self.slice_of_connection(port, mir.ty, mir.span, slice)?;
+                    let mir = match part {
+                        Some((ty, base, length)) => {
+                            let base = self.mk_mir_const_u32(base, mir.origin, mir.env, mir.span);
+                            self.arena().alloc_mir_lvalue(mir::Lvalue {
+                                id: NodeId::alloc(),
+                                origin: mir.origin,
+                                env: mir.env,
+                                span: mir.span,
+                                ty,
+                                kind: mir::LvalueKind::Index {
+                                    value: mir,
+                                    base,
+                                    length,
+                                },
+                            })
+                        }
+                        None => mir,
+                    };
                     let mir = match port.kind {
                         ModulePortKind::Port => mir,
                         ModulePortKind::IntfSignal { decl_id, env, .. } => {
@@ -1343,12 +1756,39 @@ where
                             })
                         }
                     };
-                    self.emit_mir_lvalue(mir).map(|x| x.0)
+                    let value = self.emit_mir_lvalue(mir)?.0;
+                    if let (Some(_), Some((_, _, index))) = (part, slice) {
+                        self.builder.set_name(
+                            value.0,
+                            format!("{}[{}].{}", inst.hir.name, index, port.name),
+                        );
+                    }
+                    Ok(value)
                 } else {
                     let mir = self.mir_rvalue(mapping.id(), mapping.env());
                     if mir.is_error() {
                         return Err(());
                     }
+                    let part = self.slice_of_connection(port, mir.ty, mir.span, slice)?;
+                    let mir = match part {
+                        Some((ty, base, length)) => {
+                            let base = self.mk_mir_const_u32(base, mir.origin, mir.env, mir.span);
+                            self.arena().alloc_mir_rvalue(mir::Rvalue {
+                                id: NodeId::alloc(),
+                                origin: mir.origin,
+                                env: mir.env,
+                                span: mir.span,
+                                ty,
+                                kind: mir::RvalueKind::Index {
+                                    value: mir,
+                                    base,
+                                    length,
+                                },
+                                konst: false,
+                            })
+                        }
+                        None => mir,
+                    };
                     let mir = match port.kind {
                         ModulePortKind::Port => mir,
                         ModulePortKind::IntfSignal { decl_id, env, .. } => {
@@ -1363,7 +1803,14 @@ where
                             })
                         }
                     };
-                    self.emit_mir_rvalue_mode(mir, Mode::Signal)
+                    let value = self.emit_mir_rvalue_mode(mir, Mode::Signal)?;
+                    if let (Some(_), Some((_, _, index))) = (part, slice) {
+                        self.builder.set_name(
+                            value.0,
+                            format!("{}[{}].{}", inst.hir.name, index, port.name),
+                        );
+                    }
+                    Ok(value)
                 }
             } else {
                 // Emit an auxiliary signal with the default value for this
@@ -1686,6 +2133,19 @@ where
                 }
             }
 
+            mir::RvalueKind::GenSignal { gen, index, decl } => {
+                let id = AccessedNode::Gen(gen, index, decl);
+                let sig = match self.shadows.get(&id).cloned() {
+                    Some(sig) => sig,
+                    None => self.emitted_genblk_value(id, mir.span)?,
+                };
+                if mode_hint == Mode::Signal && self.llhd_type(sig.0).is_signal() {
+                    return Ok((sig, Mode::Signal));
+                } else {
+                    self.emit_prb_or_var(sig)
+                }
+            }
+
             mir::RvalueKind::Intf(_) => {
                 self.emit(
                     DiagBuilder2::error("interface cannot be used in an expression").span(mir.span),
@@ -1923,13 +2383,19 @@ where
             mir::RvalueKind::Repeat(times, value) => {
                 let width = value.ty.simple_bit_vector(self.cx, value.span).size;
                 let value = self.emit_mir_rvalue(value)?;
-                let llty = self.emit_type_both(mir.ty)?;
-                let mut result = self.emit_zero_for_type_both(llty);
+                let llty = self.emit_type(mir.ty)?;
+                let mut result = self.emit_zero_for_type(&llty);
                 for i in 0..times {
-                    result = self.mk_ins_slice(result, value, i * width, width);
+                    result = self
+                        .builder
+                        .ins()
+                        .ins_slice(result, value.0, i * width, width);
                 }
-                self.builder.set_name(result.0, "repeat".to_string());
-                result
+                self.builder.set_name(result, "repeat".to_string());
+                (
+                    result,
+                    circt::comb::ReplicateOp::new(self.mlir_builder, value.1, times).into(),
+                )
             }
 
             mir::RvalueKind::Shift {
@@ -1969,16 +2435,38 @@ where
             mir::RvalueKind::Reduction { op, arg } => {
                 let width = arg.ty.simple_bit_vector(self.cx, arg.span).size;
                 let arg = self.emit_mir_rvalue(arg)?;
-                let mut value = self.mk_ext_slice_const_offset(arg, 0, 1);
-                for i in 1..width {
-                    let bit = self.mk_ext_slice_const_offset(arg, i, 1);
+                let bits: Vec<_> = (0..width)
+                    .map(|i| self.mk_ext_slice_const_offset(arg, i, 1))
+                    .collect();
+
+                // LLHD only has binary operations, so chain the bits.
+                let mut value = bits[0].0;
+                for bit in &bits[1..] {
                     value = match op {
-                        mir::BinaryBitwiseOp::And => self.mk_and(value, bit),
-                        mir::BinaryBitwiseOp::Or => self.mk_or(value, bit),
-                        mir::BinaryBitwiseOp::Xor => self.mk_xor(value, bit),
+                        mir::BinaryBitwiseOp::And => self.builder.ins().and(value, bit.0),
+                        mir::BinaryBitwiseOp::Or => self.builder.ins().or(value, bit.0),
+                        mir::BinaryBitwiseOp::Xor => self.builder.ins().xor(value, bit.0),
                     };
                 }
-                value
+
+                // The comb dialect reduces all bits in a single operation.
+                // An XOR over all bits of an integer is its parity.
+                let mbits = bits.iter().map(|bit| bit.1);
+                let mvalue: Option<mlir::Value> = match op {
+                    mir::BinaryBitwiseOp::Xor if mlir::is_integer_type(arg.1.ty()) => {
+                        Some(circt::comb::ParityOp::new(self.mlir_builder, arg.1).into())
+                    }
+                    mir::BinaryBitwiseOp::And => {
+                        circt::comb::AndOp::with_operands(self.mlir_builder, mbits).map(Into::into)
+                    }
+                    mir::BinaryBitwiseOp::Or => {
+                        circt::comb::OrOp::with_operands(self.mlir_builder, mbits).map(Into::into)
+                    }
+                    mir::BinaryBitwiseOp::Xor => {
+                        circt::comb::XorOp::with_operands(self.mlir_builder, mbits).map(Into::into)
+                    }
+                };
+                (value, mvalue.unwrap())
             }
 
             mir::RvalueKind::Assignment {
@@ -2209,6 +2697,23 @@ where
         base: &'gcx mir::Rvalue<'gcx>,
         length: usize,
     ) -> Result<HybridValue> {
+        // Selections at a constant offset which lie entirely within an integer
+        // value map directly to an extraction, without shifting the value.
+        if ty.coalesces_to_llhd_scalar() && self.llhd_type(value.0).is_int() {
+            let width = self.llhd_type(value.0).unwrap_int();
+            let length = std::cmp::max(1, length);
+            let offset = match base.kind {
+                mir::RvalueKind::Const(k) => match k.kind {
+                    ValueKind::Int(ref v, ..) => v.to_usize(),
+                    _ => None,
+                },
+                _ => None,
+            };
+            if let Some(offset) = offset.filter(|&o| o + length <= width) {
+                return Ok(self.mk_ext_slice_static(value, offset, length));
+            }
+        }
+
         let base = self.emit_mir_rvalue(base)?;
         if ty.coalesces_to_llhd_scalar() {
             let length = std::cmp::max(1, length);
@@ -2302,6 +2807,16 @@ where
                 ))
             }
 
+            // Declarations in other generate loop iterations are looked up by
+            // the iteration they were emitted in.
+            mir::LvalueKind::GenSignal { gen, index, decl } => {
+                let id = AccessedNode::Gen(gen, index, decl);
+                Ok((
+                    self.emitted_genblk_value(id, mir.span)?,
+                    self.shadows.get(&id).cloned(),
+                ))
+            }
+
             // Interface signals require special care, because they are emitted
             // in a transposed fashion.
             mir::LvalueKind::IntfSignal(value, signal) => self.emit_lvalue_interface(value, signal),
@@ -2456,12 +2971,27 @@ where
         match hir.kind {
             hir::StmtKind::Null => (),
             hir::StmtKind::Block(ref ids) => {
-                for &id in ids {
-                    self.emit_stmt(id, env)?;
+                if let Some(label) = hir.label {
+                    self.disable_stack.push((label.value, None));
+                }
+                let result = ids.iter().try_for_each(|&id| self.emit_stmt(id, env));
+                let exit_blk = match hir.label {
+                    Some(_) => self.disable_stack.pop().and_then(|(_, blk)| blk),
+                    None => None,
+                };
+                result?;
+                if let Some(exit_blk) = exit_blk {
+                    if !self.terminated {
+                        self.mk_br(exit_blk);
+                    }
+                    self.append_to(exit_blk);
                 }
             }
             hir::StmtKind::Assign { lhs, rhs, kind } => {
                 // Map the assignment to an MIR node.
+                if !matches!(kind, hir::AssignKind::Block(op) if op != ast::AssignOp::Identity) {
+                    hir::analysis::check_enum_assign(self.cx, lhs, rhs, env)?;
+                }
                 let assign_mir =
                     self.mir_assignment_from_procedural(stmt_id, lhs, rhs, env, hir.span, kind);
                 debug!("Procedural assignment: {:#?}", assign_mir);
@@ -2594,6 +3124,16 @@ where
                 // Emit the actual statement.
                 self.emit_stmt(stmt, env)?;
             }
+            hir::StmtKind::Timed {
+                control: hir::TimingControl::CycleDelay(..),
+                ..
+            } => {
+                self.emit(
+                    DiagBuilder2::error("unsupported: cycle delay outside of a clocking block")
+                        .span(hir.span()),
+                );
+                return Err(());
+            }
             hir::StmtKind::Timed {
                 control: hir::TimingControl::ImplicitEvent,
                 stmt,
@@ -2637,7 +3177,22 @@ where
                 self.mk_br(final_blk);
                 self.append_to(final_blk);
             }
-            hir::StmtKind::Loop { kind, body } => {
+            hir::StmtKind::Loop {
+                kind: hir::LoopKind::Foreach(array, ref indices),
+                body,
+            } => {
+                let exit_blk = self.mk_block(Some("loop_exit"));
+                self.break_stack.push(exit_blk);
+                let array_ty = self.type_of(array, env)?;
+                let result = self.emit_foreach_loop(env, array_ty, indices, body);
+                assert_eq!(self.break_stack.pop(), Some(exit_blk));
+                result?;
+                if !self.terminated {
+                    self.mk_br(exit_blk);
+                }
+                self.append_to(exit_blk);
+            }
+            hir::StmtKind::Loop { ref kind, body } => {
                 let body_blk = self.mk_block(Some("loop_body"));
                 let exit_blk = self.mk_block(Some("loop_exit"));
                 self.continue_stack.push(body_blk);
@@ -2659,45 +3214,44 @@ where
                 default,
                 kind,
             } => {
+                // If the selector is a constant, only emit the branch taken.
+                let expr_mir = self.mir_rvalue(expr, env);
+                if expr_mir.is_const() && !expr_mir.is_error() {
+                    let selector = self.const_mir_rvalue(Ref(expr_mir));
+                    if let Some(taken) =
+                        fold_const_case(self.cx, selector, ways, default, kind, env)
+                    {
+                        if let Some(stmt) = taken {
+                            self.emit_stmt(stmt, env)?;
+                        }
+                        return Ok(());
+                    }
+                }
+
                 let expr = self.emit_rvalue(expr, env)?;
                 let final_blk = self.mk_block(Some("case_exit"));
                 for &(ref way_exprs, stmt) in ways {
                     let mut last_check = None;
                     for &way_expr in way_exprs {
-                        // Determine the constant value of the label.
-                        let way_const = self.constant_value_of(way_expr, env);
-                        let (_, special_bits, x_bits) = match &way_const.kind {
-                            ValueKind::Int(v, s, x) => (v, s, x),
-                            _ => panic!("case constant evaluates to non-integer"),
-                        };
-                        let way_expr = self.emit_const(way_const, env, self.span(way_expr))?;
-                        let way_width = self.llhd_type(way_expr.0).unwrap_int();
-
-                        // Generate the comparison mask based on the case kind.
-                        let mask = match kind {
-                            ast::CaseKind::Normal => None,
-                            ast::CaseKind::DontCareZ => {
-                                let mut mask = special_bits.clone();
-                                mask.difference(x_bits);
-                                mask.negate();
-                                Some(mask)
-                            }
-                            ast::CaseKind::DontCareXZ => {
-                                let mut mask = special_bits.clone();
-                                mask.negate();
-                                Some(mask)
-                            }
+                        // Determine the constant value of the label. Labels
+                        // that are not constant are compared as they are.
+                        let way_mir = self.mir_rvalue(way_expr, env);
+                        let (way_expr, mask) = if way_mir.is_const() {
+                            let way_const = self.constant_value_of(way_expr, env);
+                            let (_, special_bits, x_bits) = match &way_const.kind {
+                                ValueKind::Int(v, s, x) => (v, s, x),
+                                _ => panic!("case constant evaluates to non-integer"),
+                            };
+                            let way_expr = self.emit_const(way_const, env, self.span(way_expr))?;
+                            let way_width = self.llhd_type(way_expr.0).unwrap_int();
+
+                            // Generate the comparison mask based on the case kind.
+                            let mask = case_label_mask(kind, special_bits, x_bits)
+                                .map(|mask| self.mk_const_int(way_width, &mask));
+                            (way_expr, mask)
+                        } else {
+                            (self.emit_rvalue(way_expr, env)?, None)
                         };
-                        let mask = mask.map(|bits| {
-                            let mut mask = BigInt::zero();
-                            for b in &bits {
-                                mask <<= 1;
-                                if b {
-                                    mask |= BigInt::one();
-                                }
-                            }
-                            self.mk_const_int(way_width, &mask)
-                        });
 
                         // Filter the comparison values through the mask.
                         let (lhs, rhs) = match mask {
@@ -2729,60 +3283,147 @@ where
                 self.append_to(final_blk);
             }
 
-            hir::StmtKind::Ast(ast) => {
-                self.emit_stmt_ast(ast, env)?;
+            hir::StmtKind::Assert {
+                cond,
+                pass_stmt,
+                fail_stmt,
+                ..
+            } => {
+                // The default fail action of an assertion reports an error at
+                // simulation time. LLHD has no means to do that, so a failing
+                // assertion without a fail action simply falls through.
+                let pass_blk = self.mk_block(Some("assert_pass"));
+                let fail_blk = self.mk_block(Some("assert_fail"));
+                let cond = self.emit_rvalue_bool(cond, env)?;
+                self.mk_cond_br(cond, pass_blk, fail_blk);
+                let final_blk = self.mk_block(Some("assert_exit"));
+                self.append_to(pass_blk);
+                if let Some(pass_stmt) = pass_stmt {
+                    self.emit_stmt(pass_stmt, env)?;
+                }
+                self.mk_br(final_blk);
+                self.append_to(fail_blk);
+                if let Some(fail_stmt) = fail_stmt {
+                    self.emit_stmt(fail_stmt, env)?;
+                }
+                self.mk_br(final_blk);
+                self.append_to(final_blk);
+            }
+
+            hir::StmtKind::Wait { cond, stmt } => {
+                // Check the condition, and wait for any of its inputs to change
+                // if it does not hold yet.
+                let check_blk = self.mk_block(Some("wait_check"));
+                let trigger_blk = self.mk_block(Some("wait_trigger"));
+                let done_blk = self.mk_block(Some("wait_done"));
+                self.mk_br(check_blk);
+                self.append_to(check_blk);
+                self.flush_mir(); // ensure we don't reuse earlier expr probe
+                self.emit_shadow_update();
+                let cond_value = self.emit_rvalue_bool(cond, env)?;
+                self.mk_cond_br(cond_value, done_blk, trigger_blk);
+                self.append_to(trigger_blk);
+                let acc = self.accessed_nodes(cond, env)?;
+                let trigger_on: Vec<_> = acc
+                    .read
+                    .iter()
+                    .map(|&id| self.emitted_value(id).clone())
+                    .collect();
+                self.mk_wait(check_blk, trigger_on, None);
+                self.append_to(done_blk);
+
+                // Emit the actual statement.
+                self.emit_stmt(stmt, env)?;
             }
 
-            _ => {
-                error!("{:#?}", hir);
-                return self.unimp_msg("code generation for", hir);
+            // Forked processes are executed sequentially, so by the time we
+            // reach a wait fork, all of them have already completed.
+            hir::StmtKind::WaitFork => (),
+
+            // For the same reason, there are no running forked processes left
+            // to be disabled.
+            hir::StmtKind::DisableFork => (),
+
+            hir::StmtKind::Disable(name) => {
+                let index = match self
+                    .disable_stack
+                    .iter()
+                    .rposition(|&(n, _)| n == name.value)
+                {
+                    Some(index) => index,
+                    None => {
+                        self.emit(
+                            DiagBuilder2::error(format!(
+                                "unsupported: disable of `{}`",
+                                name.value
+                            ))
+                            .span(name.span)
+                            .add_note("Only enclosing named blocks can be disabled"),
+                        );
+                        return Err(());
+                    }
+                };
+                let exit_blk = match self.disable_stack[index].1 {
+                    Some(blk) => blk,
+                    None => {
+                        let blk = self.mk_block(Some("disable_exit"));
+                        self.disable_stack[index].1 = Some(blk);
+                        blk
+                    }
+                };
+                self.mk_br(exit_blk);
             }
-        }
-        Ok(())
-    }
 
-    /// Emit the code for a statement for which no HIR node exists.
-    fn emit_stmt_ast(&mut self, stmt: &ast::Stmt, env: ParamEnv) -> Result<()> {
-        match &stmt.kind {
-            ast::ReturnStmt(None) => {
+            hir::StmtKind::ProcAssign { .. } | hir::StmtKind::ProcDeassign { .. } => {
+                self.emit(
+                    DiagBuilder2::error("unsupported: procedural continuous assignment")
+                        .span(hir.span()),
+                );
+                return Err(());
+            }
+            hir::StmtKind::Force { .. } | hir::StmtKind::Release { .. } => {
+                self.emit(
+                    DiagBuilder2::error("unsupported: force and release statements")
+                        .span(hir.span()),
+                );
+                return Err(());
+            }
+
+            hir::StmtKind::Return(None) => {
                 self.mk_ret(None);
             }
-            ast::ReturnStmt(Some(expr)) => {
-                let expr = self.emit_rvalue(expr.id(), env)?;
+            hir::StmtKind::Return(Some(expr)) => {
+                let expr = self.emit_rvalue(expr, env)?;
                 self.mk_ret(Some(expr));
             }
-            ast::BreakStmt => match self.break_stack.last() {
+            hir::StmtKind::Break => match self.break_stack.last() {
                 Some(&block) => {
                     self.mk_br(block);
                 }
                 None => {
                     self.emit(
                         DiagBuilder2::error("break statement outside of loop")
-                            .span(stmt.human_span()),
+                            .span(hir.human_span()),
                     );
                     return Err(());
                 }
             },
-            ast::ContinueStmt => match self.continue_stack.last() {
+            hir::StmtKind::Continue => match self.continue_stack.last() {
                 Some(&block) => {
                     self.mk_br(block);
                 }
                 None => {
                     self.emit(
                         DiagBuilder2::error("continue statement outside of loop")
-                            .span(stmt.human_span()),
+                            .span(hir.human_span()),
                     );
                     return Err(());
                 }
             },
+
             _ => {
-                error!("{:#?}", stmt);
-                bug_span!(
-                    stmt.span(),
-                    self.cx,
-                    "code generation for {} not implemented",
-                    stmt.format_indefinite()
-                );
+                error!("{:#?}", hir);
+                return self.unimp_msg("code generation for", hir);
             }
         }
         Ok(())
@@ -2792,13 +3433,13 @@ where
     fn emit_loop_stmt(
         &mut self,
         env: ParamEnv,
-        kind: hir::LoopKind,
+        kind: &hir::LoopKind,
         body: NodeId,
         body_blk: HybridBlock,
         exit_blk: HybridBlock,
     ) -> Result<()> {
         // Emit the loop initialization.
-        let repeat_var = match kind {
+        let repeat_var = match *kind {
             hir::LoopKind::Forever => None,
             hir::LoopKind::Repeat(count) => {
                 let ty = self.type_of(count, env)?;
@@ -2813,12 +3454,13 @@ where
                 self.emit_stmt(init, env)?;
                 None
             }
+            hir::LoopKind::Foreach(..) => unreachable!("foreach loops are emitted separately"),
         };
 
         // Emit the loop prologue.
         self.mk_br(body_blk);
         self.append_to(body_blk);
-        let enter_cond = match kind {
+        let enter_cond = match *kind {
             hir::LoopKind::Forever => None,
             hir::LoopKind::Repeat(_) => {
                 let (repeat_var, ty) = repeat_var.clone().unwrap();
@@ -2830,6 +3472,7 @@ where
             hir::LoopKind::While(cond) => Some(self.emit_rvalue_bool(cond, env)?),
             hir::LoopKind::Do(_) => None,
             hir::LoopKind::For(_, cond, _) => Some(self.emit_rvalue_bool(cond, env)?),
+            hir::LoopKind::Foreach(..) => unreachable!(),
         };
         if let Some(enter_cond) = enter_cond {
             let entry_blk = self.mk_block(Some("loop_continue"));
@@ -2841,7 +3484,7 @@ where
         self.emit_stmt(body, env)?;
 
         // Emit the epilogue.
-        let continue_cond = match kind {
+        let continue_cond = match *kind {
             hir::LoopKind::Forever => None,
             hir::LoopKind::Repeat(_) => {
                 let (repeat_var, ty) = repeat_var.clone().unwrap();
@@ -2857,6 +3500,7 @@ where
                 self.emit_rvalue(step, env)?;
                 None
             }
+            hir::LoopKind::Foreach(..) => unreachable!(),
         };
         if !self.terminated {
             match continue_cond {
@@ -2868,6 +3512,89 @@ where
         Ok(())
     }
 
+    /// Emit the code for a `foreach` loop.
+    ///
+    /// Emits one nested loop for each index variable, starting with the
+    /// outermost. Each loop iterates from the left to the right bound of the
+    /// corresponding array dimension. The innermost loop executes the body.
+    fn emit_foreach_loop(
+        &mut self,
+        env: ParamEnv,
+        array_ty: &'gcx UnpackedType<'gcx>,
+        indices: &[NodeId],
+        body: NodeId,
+    ) -> Result<()> {
+        let (&index_id, rest) = match indices.split_first() {
+            Some(x) => x,
+            None => return self.emit_stmt(body, env),
+        };
+        let index = match self.hir_of(index_id)? {
+            HirNode::ForeachIndex(x) => x,
+            _ => unreachable!(),
+        };
+
+        // Determine the bounds of the dimension being iterated over.
+        let range = match array_ty
+            .dims()
+            .nth(index.index)
+            .and_then(|dim| dim.get_range())
+        {
+            Some(range) => range,
+            None => {
+                self.emit(
+                    DiagBuilder2::error(format!(
+                        "cannot iterate over dimension {} of `{}`",
+                        index.index, array_ty
+                    ))
+                    .span(index.human_span())
+                    .add_note("Only arrays with fixed-size dimensions can be iterated over."),
+                );
+                return Err(());
+            }
+        };
+        let lo = BigInt::from(range.offset);
+        let hi = BigInt::from(range.offset + range.size as isize - 1);
+        let (first, last) = match range.dir {
+            ty::RangeDir::Up => (lo, hi),
+            ty::RangeDir::Down => (hi, lo),
+        };
+
+        // Allocate the index variable.
+        let first = self.mk_const_int(32, &first);
+        let var = self.mk_var(first);
+        self.builder.set_name(var.0, index.name.value.to_string());
+        self.set_emitted_value(index_id, var);
+
+        // Emit the body, which may be the next nested loop.
+        let body_blk = self.mk_block(Some("loop_body"));
+        let step_blk = self.mk_block(Some("loop_step"));
+        let exit_blk = self.mk_block(Some("loop_exit"));
+        self.mk_br(body_blk);
+        self.append_to(body_blk);
+        self.continue_stack.push(step_blk);
+        let result = self.emit_foreach_loop(env, array_ty, rest, body);
+        assert_eq!(self.continue_stack.pop(), Some(step_blk));
+        result?;
+        if !self.terminated {
+            self.mk_br(step_blk);
+        }
+
+        // Advance the index variable and check if the last element is reached.
+        self.append_to(step_blk);
+        let value = self.mk_ld(var);
+        let last = self.mk_const_int(32, &last);
+        let cond = self.mk_cmp(CmpPred::Neq, value, last);
+        let one = self.mk_const_int(32, &BigInt::one());
+        let next = match range.dir {
+            ty::RangeDir::Up => self.mk_add(value, one),
+            ty::RangeDir::Down => self.mk_sub(value, one),
+        };
+        self.mk_st(var, next);
+        self.mk_cond_br(cond, body_blk, exit_blk);
+        self.append_to(exit_blk);
+        Ok(())
+    }
+
     /// Emit the code for a variable declaration statement, given its HIR.
     fn emit_stmt_var_decl(
         &mut self,
@@ -2989,19 +3716,37 @@ where
     }
 
     /// Emit the code for a variable or net declaration.
+    ///
+    /// The `scope` is the hierarchical path of the enclosing generate block,
+    /// and is prepended to the name of the emitted signal.
     fn emit_varnet_decl(
         &mut self,
         decl_id: NodeId,
         ty: &'gcx UnpackedType<'gcx>,
         env: ParamEnv,
         default: Option<NodeId>,
+        scope: &str,
     ) -> Result<HybridValue> {
         // Check if this is a variable or a net declaration.
         let (is_var, name) = match self.hir_of(decl_id)? {
-            HirNode::VarDecl(x) => (x.kind.is_var(), x.name.value.as_str()),
-            HirNode::IntPort(x) => (x.kind.is_var(), x.name.value.as_str()),
+            HirNode::VarDecl(x) => (true, x.name.value),
+            HirNode::NetDecl(x) => (false, x.name.value),
+            HirNode::IntPort(x) => (x.kind.is_var(), x.name.value),
             x => unreachable!("emit_varnet_decl on HIR {:?}", x),
         };
+        let name = format!("{}{}", scope, name);
+
+        // Dynamically sized arrays have no hardware representation.
+        if ty.dims().any(|dim| dim.get_size().is_none()) {
+            self.emit(
+                DiagBuilder2::error(format!(
+                    "unsupported: `{}` of type `{}` has no fixed size",
+                    name, ty
+                ))
+                .span(self.span(decl_id)),
+            );
+            return Err(());
+        }
 
         // Differentiate between variable and net declarations, which have
         // slightly different semantics regarding their initial value.
@@ -3152,6 +3897,19 @@ where
         self.mk_ext_slice(arg, offset_value, length)
     }
 
+    /// Extract a slice at a constant offset from an integer value.
+    fn mk_ext_slice_static(
+        &mut self,
+        arg: HybridValue,
+        offset: usize,
+        length: usize,
+    ) -> HybridValue {
+        (
+            self.builder.ins().ext_slice(arg.0, offset, length),
+            circt::comb::ExtractOp::with_sizes(self.mlir_builder, arg.1, offset, length).into(),
+        )
+    }
+
     fn mk_ext_slice(
         &mut self,
         arg: HybridValue,
@@ -3259,7 +4017,9 @@ where
             concats.push(ext);
         }
         match is_int {
-            true => circt::comb::ConcatOp::new(self.mlir_builder, concats).into(),
+            true => circt::comb::ConcatOp::new(self.mlir_builder, concats)
+                .unwrap()
+                .into(),
             false => circt::hw::ArrayConcatOp::new(self.mlir_builder, concats).into(),
         }
     }
@@ -3623,10 +4383,170 @@ enum Mode {
 //     Xor,
 // }
 
+/// Compute the mask of the bits in a case label that participate in the
+/// comparison with the selector, or `None` if all bits participate.
+fn case_label_mask(
+    kind: ast::CaseKind,
+    special_bits: &bit_vec::BitVec,
+    x_bits: &bit_vec::BitVec,
+) -> Option<BigInt> {
+    let mut bits = special_bits.clone();
+    match kind {
+        ast::CaseKind::Normal => return None,
+        ast::CaseKind::DontCareZ => {
+            bits.difference(x_bits);
+        }
+        ast::CaseKind::DontCareXZ => (),
+    }
+    bits.negate();
+    let mut mask = BigInt::zero();
+    for b in &bits {
+        mask <<= 1;
+        if b {
+            mask |= BigInt::one();
+        }
+    }
+    Some(mask)
+}
+
+/// Determine which branch of a case statement is taken for a constant
+/// selector.
+///
+/// The selector and labels have already been cast to their common type, such
+/// that they are compared at the width of the widest of them.
+///
+/// Returns `None` if the branch cannot be determined at compile time, for
+/// example because a label is not constant, and `Some(None)` if no branch is
+/// taken at all.
+fn fold_const_case<'gcx>(
+    cx: &impl Context<'gcx>,
+    selector: Value<'gcx>,
+    ways: &[(Vec<NodeId>, NodeId)],
+    default: Option<NodeId>,
+    kind: ast::CaseKind,
+    env: ParamEnv,
+) -> Option<Option<NodeId>> {
+    let (selector_value, selector_special) = match &selector.kind {
+        ValueKind::Int(v, s, _) => (v, s),
+        _ => return None,
+    };
+    if selector_special.any() {
+        return None;
+    }
+    let modulus = BigInt::one() << selector.ty.get_bit_size()?;
+    let normalize = |v: &BigInt| ((v % &modulus) + &modulus) % &modulus;
+    let selector_value = normalize(selector_value);
+    for &(ref way_exprs, stmt) in ways {
+        for &way_expr in way_exprs {
+            let way_mir = cx.mir_rvalue(way_expr, env);
+            if !way_mir.is_const() || way_mir.is_error() {
+                return None;
+            }
+            let way = cx.const_mir_rvalue(Ref(way_mir));
+            let (way_value, special_bits, x_bits) = match &way.kind {
+                ValueKind::Int(v, s, x) => (v, s, x),
+                _ => return None,
+            };
+            let matches = match case_label_mask(kind, special_bits, x_bits) {
+                Some(mask) => &selector_value & &mask == normalize(way_value) & &mask,
+                None if special_bits.any() => return None,
+                None => selector_value == normalize(way_value),
+            };
+            if matches {
+                return Some(Some(stmt));
+            }
+        }
+    }
+    Some(default)
+}
+
+/// Emit a detailed description of a module's parameters.
+///
+/// Called when the PARAMS verbosity flag is set.
+fn emit_param_details<'gcx>(
+    cx: &impl Context<'gcx>,
+    id: NodeId,
+    hir: &hir::Module<'gcx>,
+) -> Result<()> {
+    println!("Parameters of `{}`:", hir.name);
+    for param in cx.module_params(id)? {
+        match param.default {
+            Some(default) => println!(
+                "  {} {} = {}",
+                param.kind,
+                param.name,
+                cx.span(default).extract()
+            ),
+            None => println!("  {} {}", param.kind, param.name),
+        }
+    }
+    Ok(())
+}
+
+/// Emit a detailed description of the drivers of a module's signals.
+///
+/// Called when the DRIVERS verbosity flag is set.
+fn emit_driver_details<'gcx>(
+    cx: &impl Context<'gcx>,
+    id: NodeId,
+    hir: &hir::Module<'gcx>,
+    env: ParamEnv,
+) -> Result<()> {
+    println!("Drivers of `{}`:", hir.name);
+    for &decl_id in &hir.block.decls {
+        let name = match cx.hir_of(decl_id)? {
+            HirNode::VarDecl(x) => x.name,
+            HirNode::NetDecl(x) => x.name,
+            _ => continue,
+        };
+        for driver_id in cx.net_drivers(id, decl_id, env)? {
+            match cx.hir_of(driver_id)? {
+                HirNode::Assign(x) => println!("  {}: assign {}", name, x.span.extract()),
+                x => println!("  {}: {}", name, x.desc()),
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Emit a detailed description of the signals read by a module's continuous
+/// assignments.
+///
+/// Called when the READS verbosity flag is set.
+fn emit_read_details<'gcx>(
+    cx: &impl Context<'gcx>,
+    hir: &hir::Module<'gcx>,
+    env: ParamEnv,
+) -> Result<()> {
+    println!("Reads of `{}`:", hir.name);
+    for &assign_id in &hir.block.assigns {
+        let assign = match cx.hir_of(assign_id)? {
+            HirNode::Assign(x) => x,
+            _ => unreachable!(),
+        };
+        let mut names = vec![];
+        for read_id in cx.expr_reads(assign.rhs, env) {
+            names.push(match cx.hir_of(read_id)? {
+                HirNode::VarDecl(x) => x.name.to_string(),
+                HirNode::NetDecl(x) => x.name.to_string(),
+                HirNode::IntPort(x) => x.name.to_string(),
+                x => x.desc_full(),
+            });
+        }
+        println!("  assign {}: {}", assign.span.extract(), names.join(", "));
+    }
+    Ok(())
+}
+
 /// Emit a detailed description of a module's ports.
 ///
 /// Called when the PORTS verbosity flag is set.
-fn emit_port_details<'gcx>(cx: &impl Context<'gcx>, hir: &hir::Module<'gcx>, env: ParamEnv) {
+fn emit_port_details<'gcx>(
+    cx: &impl Context<'gcx>,
+    id: NodeId,
+    hir: &hir::Module<'gcx>,
+    env: ParamEnv,
+) {
     trace!("Port details of {:#?}", hir.ports_new);
     println!("Ports of `{}`:", hir.name);
 
@@ -3673,6 +4593,14 @@ fn emit_port_details<'gcx>(cx: &impl Context<'gcx>, hir: &hir::Module<'gcx>, env
         // TODO: Dump the external port type.
         println!();
     }
+
+    // Dump the total port widths.
+    if let Ok(widths) = cx.module_port_widths(id, env) {
+        println!(
+            "  widths: {} input, {} output, {} inout",
+            widths.input, widths.output, widths.inout
+        );
+    }
 }
 
 /// Result of emitting a module.
@@ -0,0 +1,605 @@
+// Copyright (c) 2016-2021 Fabian Schuiki
+
+//! Analyses performed on the HIR, mainly for linting purposes.
+
+use crate::ast_map::AstNode;
+use crate::crate_prelude::*;
+use crate::hir::{HirNode, Visitor};
+use crate::value::ValueKind;
+use num::{BigInt, Integer, One, Signed, Zero};
+use std::collections::BTreeSet;
+
+/// Determine the signals for which an `always_comb` procedure infers a latch.
+///
+/// A latch is inferred for every signal that is assigned on some, but not all
+/// paths through the procedure. Variables declared within the procedure itself
+/// are not considered.
+#[moore_derive::query]
+pub(crate) fn inferred_latches<'a>(
+    cx: &impl Context<'a>,
+    proc_id: NodeId,
+    env: ParamEnv,
+) -> Result<Vec<NodeId>> {
+    let prok = match cx.hir_of(proc_id)? {
+        HirNode::Proc(x) => x,
+        _ => unreachable!(),
+    };
+    let assigned = assigned_in_stmt(cx, prok.stmt, env)?;
+    Ok(assigned
+        .any
+        .difference(&assigned.all)
+        .cloned()
+        .filter(|&id| !cx.is_parent_of(proc_id, id))
+        .collect())
+}
+
+/// Warn about a latch inferred for a signal in an `always_comb` procedure.
+///
+/// The latches of a procedure are determined separately for every parameter
+/// environment it is emitted in. Reporting them through this query ensures
+/// that each latch is reported once, however many specializations infer it.
+#[moore_derive::query]
+pub(crate) fn warn_inferred_latch<'a>(
+    cx: &impl Context<'a>,
+    proc_id: NodeId,
+    latch: NodeId,
+) -> Result<()> {
+    let desc = cx.hir_of(latch)?.desc_full();
+    cx.emit(
+        DiagBuilder2::warning(format!("latch inferred for {}", desc))
+            .span(cx.span(proc_id))
+            .add_note(format!(
+                "{} is not assigned on all paths through the `always_comb` procedure",
+                desc
+            ))
+            .span(cx.span(latch)),
+    );
+    Ok(())
+}
+
+/// The signals assigned by a statement.
+#[derive(Debug, Default)]
+struct Assigned {
+    /// Signals assigned on all paths through the statement.
+    all: BTreeSet<NodeId>,
+    /// Signals assigned on at least one path through the statement.
+    any: BTreeSet<NodeId>,
+}
+
+impl Assigned {
+    /// Combine the signals assigned by two statements executed in sequence.
+    fn sequence(mut self, other: Assigned) -> Assigned {
+        self.all.extend(other.all);
+        self.any.extend(other.any);
+        self
+    }
+
+    /// Combine the signals assigned by alternative branches. A missing branch
+    /// assigns nothing.
+    fn branches(branches: Vec<Option<Assigned>>) -> Assigned {
+        let mut result = Assigned::default();
+        let mut all: Option<BTreeSet<NodeId>> = None;
+        for branch in branches {
+            let branch = branch.unwrap_or_default();
+            all = Some(match all {
+                Some(all) => all.intersection(&branch.all).cloned().collect(),
+                None => branch.all,
+            });
+            result.any.extend(branch.any);
+        }
+        result.all = all.unwrap_or_default();
+        result
+    }
+}
+
+/// Determine the signals assigned by a statement.
+fn assigned_in_stmt<'a>(cx: &impl Context<'a>, stmt_id: NodeId, env: ParamEnv) -> Result<Assigned> {
+    let stmt = match cx.hir_of(stmt_id)? {
+        HirNode::Stmt(x) => x,
+        _ => return Ok(Assigned::default()),
+    };
+    Ok(match stmt.kind {
+        hir::StmtKind::Block(ref stmts) | hir::StmtKind::InlineGroup { ref stmts, .. } => {
+            let mut result = Assigned::default();
+            for &stmt in stmts {
+                result = result.sequence(assigned_in_stmt(cx, stmt, env)?);
+            }
+            result
+        }
+        hir::StmtKind::Assign { lhs, .. }
+        | hir::StmtKind::ProcAssign { lhs, .. }
+        | hir::StmtKind::Force { lhs, .. } => {
+            let mut targets = BTreeSet::new();
+            assigned_targets(cx, lhs, env, &mut targets)?;
+            Assigned {
+                all: targets.clone(),
+                any: targets,
+            }
+        }
+        hir::StmtKind::Timed { stmt, .. } | hir::StmtKind::Wait { stmt, .. } => {
+            assigned_in_stmt(cx, stmt, env)?
+        }
+        hir::StmtKind::If {
+            main_stmt,
+            else_stmt,
+            ..
+        } => Assigned::branches(vec![
+            Some(assigned_in_stmt(cx, main_stmt, env)?),
+            match else_stmt {
+                Some(else_stmt) => Some(assigned_in_stmt(cx, else_stmt, env)?),
+                None => None,
+            },
+        ]),
+        hir::StmtKind::Case {
+            expr,
+            ref ways,
+            default,
+            ..
+        } => {
+            let mut branches = vec![];
+            for &(_, stmt) in ways {
+                branches.push(Some(assigned_in_stmt(cx, stmt, env)?));
+            }
+            match default {
+                Some(default) => branches.push(Some(assigned_in_stmt(cx, default, env)?)),
+                None if case_labels_complete(cx, expr, ways, env) => (),
+                None => branches.push(None),
+            }
+            Assigned::branches(branches)
+        }
+        hir::StmtKind::Assert {
+            pass_stmt,
+            fail_stmt,
+            ..
+        } => Assigned::branches(vec![
+            match pass_stmt {
+                Some(pass_stmt) => Some(assigned_in_stmt(cx, pass_stmt, env)?),
+                None => None,
+            },
+            match fail_stmt {
+                Some(fail_stmt) => Some(assigned_in_stmt(cx, fail_stmt, env)?),
+                None => None,
+            },
+        ]),
+        // The body of a loop may not execute at all, unless its trip count is
+        // known to be non-zero during elaboration.
+        hir::StmtKind::Loop { ref kind, body } => {
+            let assigned = assigned_in_stmt(cx, body, env)?;
+            if loop_body_executes(cx, kind, env) {
+                assigned
+            } else {
+                Assigned {
+                    all: Default::default(),
+                    any: assigned.any,
+                }
+            }
+        }
+        hir::StmtKind::Null
+        | hir::StmtKind::WaitFork
+        | hir::StmtKind::Disable(..)
+        | hir::StmtKind::DisableFork
+        | hir::StmtKind::Break
+        | hir::StmtKind::Continue
+        | hir::StmtKind::Return(..)
+        | hir::StmtKind::ProcDeassign { .. }
+        | hir::StmtKind::Release { .. }
+        | hir::StmtKind::Expr(..)
+        | hir::StmtKind::Ast(..) => Assigned::default(),
+    })
+}
+
+/// Check whether the body of a loop executes at least once.
+///
+/// This is the case for `do`/`while` loops, `repeat` loops with a constant
+/// count greater than zero, and `for` loops whose condition holds for the
+/// constant initial values of their loop variables. Returns `false` if this
+/// cannot be decided during elaboration.
+fn loop_body_executes<'a>(cx: &impl Context<'a>, kind: &hir::LoopKind, env: ParamEnv) -> bool {
+    match *kind {
+        hir::LoopKind::Do(_) => true,
+        hir::LoopKind::Repeat(count) => {
+            let mir = cx.mir_rvalue(count, env);
+            mir.is_const()
+                && cx
+                    .const_mir_rvalue(Ref(mir))
+                    .get_int()
+                    .map_or(false, |count| count.is_positive())
+        }
+        hir::LoopKind::For(init, cond, _) => {
+            value::for_loop_enters_body(cx, init, cond, env).unwrap_or(false)
+        }
+        hir::LoopKind::Forever | hir::LoopKind::While(_) | hir::LoopKind::Foreach(..) => false,
+    }
+}
+
+/// Check whether the labels of a case statement cover every value of its
+/// selector.
+///
+/// Only labels that are constants without `x` or `z` bits are considered. The
+/// labels are compared at the common type of the selector and the labels, such
+/// that labels outside the range of the extended selector cover no value.
+fn case_labels_complete<'a>(
+    cx: &impl Context<'a>,
+    expr: NodeId,
+    ways: &[(Vec<NodeId>, NodeId)],
+    env: ParamEnv,
+) -> bool {
+    let width = match cx
+        .self_determined_type(expr, env)
+        .and_then(|ty| ty.get_bit_size())
+    {
+        Some(w) => w,
+        None => return false,
+    };
+    let common_ty = match cx.type_of(expr, env) {
+        Ok(ty) => ty,
+        Err(()) => return false,
+    };
+    let common_width = match common_ty.get_bit_size() {
+        Some(w) => w,
+        None => return false,
+    };
+    let signed = common_ty
+        .get_simple_bit_vector()
+        .map_or(false, |sbv| sbv.is_signed());
+
+    // Determine the range of values the extended selector can take.
+    let modulus = BigInt::one() << width;
+    let common_modulus = BigInt::one() << common_width;
+    let (min, max) = match signed {
+        true => (-(&modulus >> 1), &modulus >> 1),
+        false => (BigInt::zero(), modulus.clone()),
+    };
+
+    let mut covered = BTreeSet::new();
+    for &label in ways.iter().flat_map(|(labels, _)| labels) {
+        let mir = cx.mir_rvalue(label, env);
+        if !mir.is_const() || mir.ty.get_bit_size() != Some(common_width) {
+            continue;
+        }
+        match cx.const_mir_rvalue(Ref(mir)).kind {
+            ValueKind::Int(ref value, ref special, _) if special.none() => {
+                let mut value = value.mod_floor(&common_modulus);
+                if signed && value >= (&common_modulus >> 1) {
+                    value -= &common_modulus;
+                }
+                if value >= min && value < max {
+                    covered.insert(value.mod_floor(&modulus));
+                }
+            }
+            _ => (),
+        }
+    }
+    BigInt::from(covered.len()) == modulus
+}
+
+/// Determine the signals targeted by the left-hand side of an assignment.
+///
+/// Assignments to a part of a signal, e.g. an index or a struct field, are
+/// conservatively treated as assigning the entire signal.
+fn assigned_targets<'a>(
+    cx: &impl Context<'a>,
+    expr_id: NodeId,
+    env: ParamEnv,
+    into: &mut BTreeSet<NodeId>,
+) -> Result<()> {
+    let expr = match cx.hir_of(expr_id)? {
+        HirNode::Expr(x) => x,
+        _ => return Ok(()),
+    };
+    match expr.kind {
+        hir::ExprKind::Ident(..) | hir::ExprKind::Scope(..) => {
+            into.insert(cx.resolve_node(expr_id, env)?);
+        }
+        hir::ExprKind::Index(target, _) | hir::ExprKind::Field(target, _) => {
+            assigned_targets(cx, target, env, into)?;
+        }
+        hir::ExprKind::Concat(_, ref exprs) => {
+            for &expr in exprs {
+                assigned_targets(cx, expr, env, into)?;
+            }
+        }
+        _ => (),
+    }
+    Ok(())
+}
+
+/// Determine the declarations read by an expression.
+///
+/// Walks the expression tree and resolves every identifier that is read to
+/// the node it refers to. Each node is reported once, in the order in which it
+/// first appears in the expression.
+#[moore_derive::query]
+pub(crate) fn expr_reads<'a>(cx: &impl Context<'a>, expr_id: NodeId, env: ParamEnv) -> Vec<NodeId> {
+    let mut k = ExprReadsCollector {
+        cx,
+        env,
+        reads: vec![],
+    };
+    k.visit_node_with_id(expr_id, false);
+    k.reads
+}
+
+/// A visitor for the HIR that collects the declarations read by an expression.
+struct ExprReadsCollector<'a, C> {
+    cx: &'a C,
+    env: ParamEnv,
+    reads: Vec<NodeId>,
+}
+
+impl<'a, 'gcx: 'a, C> Visitor<'gcx> for ExprReadsCollector<'a, C>
+where
+    C: Context<'gcx>,
+{
+    type Context = C;
+    fn context(&self) -> &C {
+        self.cx
+    }
+
+    fn visit_expr(&mut self, expr: &'gcx hir::Expr, lvalue: bool) {
+        match expr.kind {
+            hir::ExprKind::Ident(..) | hir::ExprKind::Scope(..) => {
+                if lvalue {
+                    return;
+                }
+                if let Ok(id) = self.cx.resolve_node(expr.id, self.env) {
+                    if !self.reads.contains(&id) {
+                        self.reads.push(id);
+                    }
+                }
+            }
+            _ => hir::walk_expr(self, expr, lvalue),
+        }
+    }
+}
+
+/// Determine the drivers of a net or variable within a module.
+///
+/// Returns the continuous assignments and procedures in the module's body that
+/// assign to the given node, in the order in which they appear. Drivers within
+/// generate blocks and instantiated modules are not considered.
+#[moore_derive::query]
+pub(crate) fn net_drivers<'a>(
+    cx: &impl Context<'a>,
+    module_id: NodeId,
+    net_id: NodeId,
+    env: ParamEnv,
+) -> Result<Vec<NodeId>> {
+    let module = match cx.hir_of(module_id)? {
+        HirNode::Module(x) => x,
+        _ => unreachable!(),
+    };
+    let mut drivers = vec![];
+    for &assign_id in &module.block.assigns {
+        let assign = match cx.hir_of(assign_id)? {
+            HirNode::Assign(x) => x,
+            _ => unreachable!(),
+        };
+        let mut targets = BTreeSet::new();
+        assigned_targets(cx, assign.lhs, env, &mut targets)?;
+        if targets.contains(&net_id) {
+            drivers.push(assign_id);
+        }
+    }
+    for &proc_id in &module.block.procs {
+        let prok = match cx.hir_of(proc_id)? {
+            HirNode::Proc(x) => x,
+            _ => unreachable!(),
+        };
+        if assigned_in_stmt(cx, prok.stmt, env)?.any.contains(&net_id) {
+            drivers.push(proc_id);
+        }
+    }
+    drivers.sort_by_key(|&id| cx.span(id).begin());
+    Ok(drivers)
+}
+
+/// Check that a literal assigned to an enum-typed target is a valid variant.
+///
+/// Emits a warning if the right-hand side of the assignment is an integer
+/// literal whose value does not match any of the variants of the target's
+/// enum type. Non-literal right-hand sides are not checked.
+pub(crate) fn check_enum_assign<'a>(
+    cx: &impl Context<'a>,
+    lhs: NodeId,
+    rhs: NodeId,
+    env: ParamEnv,
+) -> Result<()> {
+    let value = match cx.hir_of(rhs)? {
+        HirNode::Expr(hir::Expr {
+            kind: hir::ExprKind::IntConst { ref value, .. },
+            ..
+        }) => value,
+        _ => return Ok(()),
+    };
+    let ty = cx.type_of(lhs, env)?;
+    let enm = match ty.get_enum() {
+        Some(x) => x,
+        None => return Ok(()),
+    };
+    for (_, variant) in &enm.variants {
+        if cx.constant_value_of(variant.id(), env).get_int() == Some(value) {
+            return Ok(());
+        }
+    }
+    cx.emit(
+        DiagBuilder2::warning(format!(
+            "value `{}` is not a variant of enum `{}`",
+            value, ty
+        ))
+        .span(cx.span(rhs))
+        .add_note(format!(
+            "Valid variants are: {}",
+            enm.variants
+                .iter()
+                .map(|(name, _)| format!("`{}`", name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    );
+    Ok(())
+}
+
+/// Warn about statements in a procedure or subroutine that can never execute.
+///
+/// This runs once per procedure or subroutine, independent of the parameter
+/// environments it is emitted in, such that every unreachable statement is
+/// reported only once. Returns `true` if all statements are reachable.
+#[moore_derive::query]
+pub(crate) fn check_unreachable_stmts<'a>(cx: &impl Context<'a>, node_id: NodeId) -> bool {
+    let stmts: Vec<NodeId> = match cx.ast_of(node_id) {
+        Ok(AstNode::SubroutineDecl(decl)) => decl
+            .items
+            .iter()
+            .flat_map(|item| match item {
+                ast::SubroutineItem::Stmt(stmt) => Some(stmt.id()),
+                _ => None,
+            })
+            .collect(),
+        _ => match cx.hir_of(node_id) {
+            Ok(HirNode::Proc(prok)) => vec![prok.stmt],
+            _ => return true,
+        },
+    };
+    let unreachable = unreachable_stmts(cx, &stmts);
+    for &(stmt, jump) in &unreachable {
+        cx.emit(
+            DiagBuilder2::warning("unreachable statement")
+                .span(cx.span(stmt))
+                .add_note("Statement follows an unconditional jump:")
+                .span(cx.span(jump)),
+        );
+    }
+    unreachable.is_empty()
+}
+
+/// Determine the statements that can never execute.
+///
+/// A statement is unreachable if it follows an unconditional `return`,
+/// `break`, or `continue` in the same sequential block. Returns pairs of the
+/// first unreachable statement in each block and the statement that jumps
+/// over it. Nested blocks are checked as well.
+fn unreachable_stmts<'a>(cx: &impl Context<'a>, stmts: &[NodeId]) -> Vec<(NodeId, NodeId)> {
+    let mut k = UnreachableCollector {
+        cx,
+        unreachable: vec![],
+    };
+    collect_unreachable_in_sequence(&mut k, stmts);
+    k.unreachable
+}
+
+/// A visitor for the HIR that collects unreachable statements.
+struct UnreachableCollector<'a, C> {
+    cx: &'a C,
+    unreachable: Vec<(NodeId, NodeId)>,
+}
+
+impl<'a, 'gcx: 'a, C> Visitor<'gcx> for UnreachableCollector<'a, C>
+where
+    C: Context<'gcx>,
+{
+    type Context = C;
+    fn context(&self) -> &C {
+        self.cx
+    }
+
+    fn visit_stmt(&mut self, stmt: &'gcx hir::Stmt) {
+        match stmt.kind {
+            hir::StmtKind::Block(ref stmts) => collect_unreachable_in_sequence(self, stmts),
+            _ => hir::walk_stmt(self, stmt),
+        }
+    }
+}
+
+/// Visit a sequence of statements, recording the first statement that follows
+/// an unconditional jump.
+fn collect_unreachable_in_sequence<'a, 'gcx: 'a, C: Context<'gcx>>(
+    k: &mut UnreachableCollector<'a, C>,
+    stmts: &[NodeId],
+) {
+    for (i, &id) in stmts.iter().enumerate() {
+        k.visit_node_with_id(id, false);
+        if is_unconditional_jump(k.cx, id) {
+            if let Some(&next) = stmts.get(i + 1) {
+                k.unreachable.push((next, id));
+            }
+            return;
+        }
+    }
+}
+
+/// Check if a statement is a `return`, `break`, `continue`, or `disable`.
+fn is_unconditional_jump<'a>(cx: &impl Context<'a>, stmt_id: NodeId) -> bool {
+    match cx.hir_of(stmt_id) {
+        Ok(HirNode::Stmt(hir::Stmt { kind, .. })) => match kind {
+            hir::StmtKind::Break
+            | hir::StmtKind::Continue
+            | hir::StmtKind::Return(..)
+            | hir::StmtKind::Disable(..) => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Check that the connections of an instance match the width of their ports.
+///
+/// Emits a warning for every connection that is narrower or wider than the
+/// port it connects to, since the value is implicitly extended or truncated.
+/// Literals and connections to instance arrays, whose widths legitimately
+/// differ from the port, are not checked.
+pub(crate) fn check_port_widths<'a>(cx: &impl Context<'a>, inst: &InstDetails<'a>) {
+    if !inst.hir.ast.dims.is_empty() {
+        return;
+    }
+    for &(Ref(port), assigned) in inst.ports.0.iter() {
+        match cx.hir_of(assigned.id()) {
+            Ok(HirNode::Expr(hir::Expr {
+                kind: hir::ExprKind::IntConst { .. },
+                ..
+            }))
+            | Ok(HirNode::Expr(hir::Expr {
+                kind: hir::ExprKind::UnsizedConst(..),
+                ..
+            })) => continue,
+            Ok(HirNode::Expr(..)) => (),
+            _ => continue,
+        }
+        let port_ty = cx.type_of_ext_port(Ref(port), inst.inner_env);
+        let conn_ty = match cx.self_determined_type(assigned.id(), assigned.env()) {
+            Some(x) => x,
+            None => continue,
+        };
+        if port_ty.is_error()
+            || conn_ty.is_error()
+            || !port_ty.coalesces_to_llhd_scalar()
+            || !conn_ty.coalesces_to_llhd_scalar()
+        {
+            continue;
+        }
+        let (port_width, conn_width) = match (port_ty.get_bit_size(), conn_ty.get_bit_size()) {
+            (Some(p), Some(c)) => (p, c),
+            _ => continue,
+        };
+        if port_width == conn_width {
+            continue;
+        }
+        let span = cx.span(assigned.id());
+        let port_name = match port.name {
+            Some(name) => format!("port `{}`", name.value),
+            None => String::from("port"),
+        };
+        cx.emit(
+            DiagBuilder2::warning(format!(
+                "width mismatch: `{}` has {} bits, but {} has {} bits",
+                span.extract(),
+                conn_width,
+                port_name,
+                port_width
+            ))
+            .span(span)
+            .add_note(format!("The {} is declared here:", port_name))
+            .span(port.span),
+        );
+    }
+}
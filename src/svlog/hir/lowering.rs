@@ -3,7 +3,11 @@
 //! Lowering of AST nodes to HIR nodes.
 
 use crate::crate_prelude::*;
-use crate::{ast_map::AstNode, hir::HirNode, resolver::DefNode};
+use crate::{
+    ast_map::AstNode,
+    hir::HirNode,
+    resolver::{DefNode, ScopedNode},
+};
 use bit_vec::BitVec;
 use num::BigInt;
 
@@ -156,29 +160,28 @@ pub(crate) fn hir_of<'a>(cx: &impl Context<'a>, node_id: NodeId) -> Result<HirNo
                     .init
                     .as_ref()
                     .map(|expr| cx.map_ast_with_parent(AstNode::Expr(expr), node_id)),
-                kind: ast::VarKind::Var,
             };
             Ok(HirNode::VarDecl(cx.arena().alloc_hir(hir)))
         }
         AstNode::NetDecl(name, decl, ty) => {
-            // TODO(fschuiki): Map this to something different than a variable.
-            let hir = hir::VarDecl {
+            let hir = hir::NetDecl {
                 id: node_id,
                 name: Spanned::new(name.name, name.name_span),
                 span: Span::union(name.span, decl.span),
+                net_type: decl.net_type,
+                kind: decl.kind,
                 ty: ty,
                 init: name
                     .init
                     .as_ref()
                     .map(|expr| cx.map_ast_with_parent(AstNode::Expr(expr), node_id)),
-                kind: ast::VarKind::Net {
-                    ty: decl.net_type,
-                    kind: decl.kind,
-                },
             };
-            Ok(HirNode::VarDecl(cx.arena().alloc_hir(hir)))
+            Ok(HirNode::NetDecl(cx.arena().alloc_hir(hir)))
         }
         AstNode::Proc(prok) => {
+            if prok.kind == ast::ProcedureKind::AlwaysFf {
+                check_always_ff_events(cx, prok)?;
+            }
             let hir = hir::Proc {
                 id: node_id,
                 span: prok.span,
@@ -236,6 +239,44 @@ pub(crate) fn hir_of<'a>(cx: &impl Context<'a>, node_id: NodeId) -> Result<HirNo
                     cond,
                     step,
                     body,
+                    label: gen.block.label,
+                },
+            };
+            Ok(HirNode::Gen(cx.arena().alloc_hir(hir)))
+        }
+        AstNode::GenCase(gen) => {
+            let expr = cx.map_ast_with_parent(AstNode::Expr(&gen.expr), node_id);
+            let mut ways = vec![];
+            let mut default = None;
+            for item in &gen.items {
+                match *item {
+                    ast::GenerateCaseItem::Default(ref block) => {
+                        if default.is_none() {
+                            default =
+                                Some(lower_module_block(cx, node_id, &block.items, false, false)?);
+                        } else {
+                            cx.emit(
+                                DiagBuilder2::error("multiple default cases")
+                                    .span(block.human_span()),
+                            );
+                        }
+                    }
+                    ast::GenerateCaseItem::Expr(ref exprs, ref block) => ways.push((
+                        exprs
+                            .iter()
+                            .map(|expr| cx.map_ast_with_parent(AstNode::Expr(expr), node_id))
+                            .collect(),
+                        lower_module_block(cx, node_id, &block.items, false, false)?,
+                    )),
+                }
+            }
+            let hir = hir::Gen {
+                id: node_id,
+                span: gen.span(),
+                kind: hir::GenKind::Case {
+                    expr,
+                    ways,
+                    default,
                 },
             };
             Ok(HirNode::Gen(cx.arena().alloc_hir(hir)))
@@ -264,12 +305,16 @@ pub(crate) fn hir_of<'a>(cx: &impl Context<'a>, node_id: NodeId) -> Result<HirNo
             };
             Ok(HirNode::Typedef(cx.arena().alloc_hir(hir)))
         }
-        AstNode::ContAssign(_, lhs, rhs) => {
+        AstNode::ContAssign(assign, lhs, rhs) => {
             let hir = hir::Assign {
                 id: node_id,
                 span: Span::union(lhs.span(), rhs.span()),
                 lhs: cx.map_ast_with_parent(AstNode::Expr(lhs), node_id),
                 rhs: cx.map_ast_with_parent(AstNode::Expr(rhs), node_id),
+                delay: assign
+                    .delay_control
+                    .as_ref()
+                    .map(|dc| cx.map_ast_with_parent(AstNode::Expr(&dc.expr), node_id)),
             };
             Ok(HirNode::Assign(cx.arena().alloc_hir(hir)))
         }
@@ -283,7 +328,6 @@ pub(crate) fn hir_of<'a>(cx: &impl Context<'a>, node_id: NodeId) -> Result<HirNo
                     .init
                     .as_ref()
                     .map(|expr| cx.map_ast_with_parent(AstNode::Expr(expr), ty)),
-                kind: ast::VarKind::Var,
             };
             Ok(HirNode::VarDecl(cx.arena().alloc_hir(hir)))
         }
@@ -306,6 +350,18 @@ pub(crate) fn hir_of<'a>(cx: &impl Context<'a>, node_id: NodeId) -> Result<HirNo
         AstNode::CallArg(x) => Ok(HirNode::CallArg(x)),
         AstNode::Any(ast) => match ast.as_all() {
             ast::AllNode::SubroutinePort(x) => Ok(HirNode::SubroutinePort(x)),
+            ast::AllNode::ForeachIndex(x) => Ok(HirNode::ForeachIndex(x)),
+            ast::AllNode::GenerateBlock(x) => {
+                let hir = hir::Gen {
+                    id: node_id,
+                    span: x.span(),
+                    kind: hir::GenKind::Block {
+                        body: lower_module_block(cx, node_id, &x.items, false, false)?,
+                        label: x.label.unwrap(),
+                    },
+                };
+                Ok(HirNode::Gen(cx.arena().alloc_hir(hir)))
+            }
             _ => {
                 error!("{:#?}", ast);
                 bug_span!(ast.span(), cx, "lowering of {} to hir not implemented", ast);
@@ -497,6 +553,14 @@ fn lower_module_block_into<'gcx>(
             ast::ItemData::GenerateRegion(_span, ref items) => {
                 lower_module_block_into(cx, items, allow_ports, allow_modports, into)?;
             }
+            ast::ItemData::GenerateBlock(ref block) if block.label.is_some() => {
+                let id = cx.map_ast_with_parent(AstNode::Any(block), into.last_rib);
+                into.last_rib = id;
+                into.gens.push(id);
+            }
+            ast::ItemData::GenerateBlock(ref block) => {
+                lower_module_block_into(cx, &block.items, false, false, into)?;
+            }
             ast::ItemData::ParamDecl(ref param) => {
                 into.last_rib = alloc_param_decl(cx, param, into.last_rib, &mut into.params);
             }
@@ -598,6 +662,14 @@ fn lower_type<'gcx>(
             cx.map_ast_with_parent(AstNode::Type(ty.as_ref()), node_id),
             name,
         ),
+        ast::ScopedType {
+            ref ty,
+            member: true,
+            name,
+        } => hir::TypeKind::ScopeMember(
+            cx.map_ast_with_parent(AstNode::Type(ty.as_ref()), node_id),
+            name,
+        ),
         ast::EnumType(ref enm) => {
             let repr_ty = &enm.base_type;
             let names = &enm.variants;
@@ -663,8 +735,7 @@ fn lower_type<'gcx>(
         | ast::RealType
         | ast::RealtimeType
         | ast::SpecializedType(..)
-        | ast::ForwardType { .. }
-        | ast::ScopedType { .. } => {
+        | ast::ForwardType { .. } => {
             error!("{:#?}", ty);
             bug_span!(
                 ty.span(),
@@ -683,6 +754,12 @@ fn lower_type<'gcx>(
                     cx.map_ast_with_parent(AstNode::Expr(rhs), node_id),
                 );
             }
+            ast::TypeDim::Expr(ref size) => {
+                kind = hir::TypeKind::PackedArraySize(
+                    Box::new(kind),
+                    cx.map_ast_with_parent(AstNode::Expr(size), node_id),
+                );
+            }
             _ => {
                 cx.emit(
                     DiagBuilder2::error(format!(
@@ -690,7 +767,10 @@ fn lower_type<'gcx>(
                         dim.desc_full()
                     ))
                     .span(ty.human_span())
-                    .add_note("packed array dimensions can only be given as range, e.g. `[31:0]`"),
+                    .add_note(
+                        "packed array dimensions can only be given as range or size, e.g. \
+                         `[31:0]` or `[32]`",
+                    ),
                 );
                 return Err(());
             }
@@ -843,6 +923,31 @@ fn lower_expr_inner<'gcx>(
             }
         }
 
+        ast::LiteralExpr(Lit::Number(int, Some(frac))) => {
+            hir::ExprKind::RealConst(parse_fixed_point_number(cx, expr.span, int, Some(frac))?)
+        }
+
+        ast::LiteralExpr(Lit::Real(int, frac, exp)) => {
+            let value = parse_fixed_point_number(cx, expr.span, int, frac)?;
+            let exp: i32 = match exp.as_str().parse() {
+                Ok(x) => x,
+                Err(e) => {
+                    cx.emit(
+                        DiagBuilder2::error(format!("`{}` is not a valid exponent", exp))
+                            .span(expr.span)
+                            .add_note(format!("{}", e)),
+                    );
+                    return Err(());
+                }
+            };
+            let scale = num::pow(num::BigInt::from(10), exp.unsigned_abs() as usize);
+            hir::ExprKind::RealConst(if exp < 0 {
+                value / scale
+            } else {
+                value * scale
+            })
+        }
+
         ast::LiteralExpr(Lit::Time(int, frac, unit)) => {
             use syntax::token::TimeUnit;
             let mut value = parse_fixed_point_number(cx, expr.span, int, frac)?;
@@ -1096,6 +1201,43 @@ fn lower_expr_inner<'gcx>(
                 .map(|expr| cx.map_ast_with_parent(AstNode::Expr(expr), node_id))
                 .collect(),
         ),
+        ast::StreamConcatExpr {
+            dir,
+            ref slice,
+            ref exprs,
+        } => {
+            let slice_size = match slice {
+                Some(ast::StreamConcatSlice::Expr(expr)) => {
+                    Some(cx.map_ast_with_parent(AstNode::Expr(expr), node_id))
+                }
+                Some(ast::StreamConcatSlice::Type(ty)) => {
+                    cx.emit(
+                        DiagBuilder2::error(
+                            "unsupported: type as slice size of streaming concatenation",
+                        )
+                        .span(ty.span()),
+                    );
+                    return Err(());
+                }
+                None => None,
+            };
+            let mut lowered = vec![];
+            for expr in exprs {
+                if let Some(ref range) = expr.range {
+                    cx.emit(
+                        DiagBuilder2::error("unsupported: `with` range in streaming concatenation")
+                            .span(range.span()),
+                    );
+                    return Err(());
+                }
+                lowered.push(cx.map_ast_with_parent(AstNode::Expr(&expr.expr), node_id));
+            }
+            hir::ExprKind::StreamConcat {
+                dir,
+                slice_size,
+                exprs: lowered,
+            }
+        }
         ast::CastExpr(ref ty, ref expr) => {
             // Catch the corner case where a size cast looks like a type cast.
             if let ast::NamedType(n) = ty.kind.data {
@@ -1170,6 +1312,9 @@ fn lower_expr_inner<'gcx>(
             lhs: lhs.as_ref(),
             rhs: rhs.as_ref(),
         },
+        ast::EmptyQueueExpr => hir::ExprKind::EmptyQueue,
+        ast::NullExpr => hir::ExprKind::NullLiteral,
+        ast::DollarExpr => hir::ExprKind::Unbounded,
         _ => hir::ExprKind::Ast(expr),
     })
 }
@@ -1230,15 +1375,9 @@ fn lower_stmt_inner<'a>(
                         cx.map_ast_with_parent(AstNode::EventExpr(expr), node_id),
                     ),
                 },
-                _ => {
-                    debug!("{:#?}", stmt);
-                    bug_span!(
-                        stmt.span(),
-                        cx,
-                        "lowering of timing control {} to hir not implemented",
-                        stmt
-                    );
-                }
+                ast::TimingControl::Cycle(ref cd) => hir::TimingControl::CycleDelay(
+                    cx.map_ast_with_parent(AstNode::Expr(&cd.expr), node_id),
+                ),
             };
             hir::StmtKind::Timed {
                 control,
@@ -1285,6 +1424,21 @@ fn lower_stmt_inner<'a>(
                 body: cx.map_ast_with_parent(AstNode::Stmt(body), init),
             }
         }
+        ast::ForeachStmt(ref array, ref indices, ref body) => {
+            let array = cx.map_ast_with_parent(AstNode::Expr(array), node_id);
+            let mut next_rib = node_id;
+            let indices = indices
+                .iter()
+                .map(|index| {
+                    next_rib = cx.map_ast_with_parent(AstNode::Any(index), next_rib);
+                    next_rib
+                })
+                .collect();
+            hir::StmtKind::Loop {
+                kind: hir::LoopKind::Foreach(array, indices),
+                body: cx.map_ast_with_parent(AstNode::Stmt(body), next_rib),
+            }
+        }
         ast::VarDeclStmt(ref decls) => {
             let mut stmts = vec![];
             let parent = cx.parent_node_id(node_id).unwrap();
@@ -1337,6 +1491,34 @@ fn lower_stmt_inner<'a>(
                     )),
                 }
             }
+
+            // Warn about `x` bits in `casez` labels, which are compared
+            // literally instead of being treated as don't-care.
+            if kind == ast::CaseKind::DontCareZ {
+                for &(ref exprs, _) in &ways {
+                    for &expr in exprs {
+                        let has_x = match cx.hir_of(expr) {
+                            Ok(HirNode::Expr(hir::Expr {
+                                kind: hir::ExprKind::IntConst { ref x_bits, .. },
+                                ..
+                            })) => x_bits.any(),
+                            _ => false,
+                        };
+                        if has_x {
+                            cx.emit(
+                                DiagBuilder2::warning(
+                                    "`x` bits in `casez` label are not don't-care",
+                                )
+                                .span(cx.span(expr))
+                                .add_note(
+                                    "Only `z` and `?` bits are ignored by `casez`; use `?` \
+                                         or `casex` instead.",
+                                ),
+                            );
+                        }
+                    }
+                }
+            }
             hir::StmtKind::Case {
                 expr,
                 ways,
@@ -1344,13 +1526,124 @@ fn lower_stmt_inner<'a>(
                 kind,
             }
         }
-        ast::AssertionStmt { .. } => {
+        ast::CaseStmt {
+            mode: ast::CaseMode::Pattern,
+            ..
+        } => {
             cx.emit(
-                DiagBuilder2::warning("unsupported: immediate assertion; ignored")
+                DiagBuilder2::error("unsupported: pattern matching `case ... matches`")
+                    .span(stmt.human_span())
+                    .add_note("Tagged unions and pattern variables are not yet supported."),
+            );
+            return Err(());
+        }
+        ast::AssertionStmt(ref assertion) => match assertion.data {
+            ast::AssertionData::Immediate(ref blocking) => {
+                let map_stmt = |stmt: &'a ast::Stmt<'a>| {
+                    Some(cx.map_ast_with_parent(AstNode::Stmt(stmt), node_id))
+                };
+                let map_action = |action: &'a ast::AssertionActionBlock<'a>| match *action {
+                    ast::AssertionActionBlock::Positive(ref pass) => (map_stmt(pass), None),
+                    ast::AssertionActionBlock::Negative(ref fail) => (None, map_stmt(fail)),
+                    ast::AssertionActionBlock::Both(ref pass, ref fail) => {
+                        (map_stmt(pass), map_stmt(fail))
+                    }
+                };
+                let (kind, cond, (pass_stmt, fail_stmt)) = match *blocking {
+                    ast::BlockingAssertion::Assert(ref cond, ref action) => {
+                        (hir::AssertKind::Assert, cond, map_action(action))
+                    }
+                    ast::BlockingAssertion::Assume(ref cond, ref action) => {
+                        (hir::AssertKind::Assume, cond, map_action(action))
+                    }
+                    ast::BlockingAssertion::Cover(ref cond, ref stmt) => {
+                        (hir::AssertKind::Cover, cond, (map_stmt(stmt), None))
+                    }
+                };
+                hir::StmtKind::Assert {
+                    kind,
+                    cond: cx.map_ast_with_parent(AstNode::Expr(cond), node_id),
+                    pass_stmt,
+                    fail_stmt,
+                }
+            }
+            ast::AssertionData::Deferred(..) => {
+                cx.emit(
+                    DiagBuilder2::warning("unsupported: deferred assertion; ignored")
+                        .span(stmt.human_span()),
+                );
+                hir::StmtKind::Null
+            }
+            ast::AssertionData::Concurrent(..) => {
+                cx.emit(
+                    DiagBuilder2::warning("unsupported: concurrent assertion; ignored")
+                        .span(stmt.human_span()),
+                );
+                hir::StmtKind::Null
+            }
+        },
+        ast::WaitExprStmt(ref cond, ref inner_stmt) => hir::StmtKind::Wait {
+            cond: cx.map_ast_with_parent(AstNode::Expr(cond), node_id),
+            stmt: cx.map_ast_with_parent(AstNode::Stmt(inner_stmt), node_id),
+        },
+        ast::WaitForkStmt => hir::StmtKind::WaitFork,
+        ast::BreakStmt => hir::StmtKind::Break,
+        ast::ContinueStmt => hir::StmtKind::Continue,
+        ast::ReturnStmt(ref expr) => hir::StmtKind::Return(
+            expr.as_ref()
+                .map(|expr| cx.map_ast_with_parent(AstNode::Expr(expr), node_id)),
+        ),
+        // A fork with a single process that is joined behaves like a
+        // sequential block.
+        ast::ParallelBlock(ref stmts, ast::JoinKind::All)
+        | ast::ParallelBlock(ref stmts, ast::JoinKind::Any)
+            if stmts.len() <= 1 =>
+        {
+            let mut next_rib = node_id;
+            hir::StmtKind::Block(
+                stmts
+                    .iter()
+                    .map(|stmt| {
+                        let id = cx.map_ast_with_parent(AstNode::Stmt(stmt), next_rib);
+                        next_rib = id;
+                        id
+                    })
+                    .collect(),
+            )
+        }
+        ast::ParallelBlock(..) => {
+            cx.emit(
+                DiagBuilder2::error("unsupported: fork with multiple concurrent processes")
                     .span(stmt.human_span()),
             );
-            hir::StmtKind::Null
+            return Err(());
         }
+        ast::ProcAssignStmt { ref lhs, ref rhs } => hir::StmtKind::ProcAssign {
+            lhs: cx.map_ast_with_parent(AstNode::Expr(lhs), node_id),
+            rhs: cx.map_ast_with_parent(AstNode::Expr(rhs), node_id),
+        },
+        ast::ProcDeassignStmt(ref lhs) => hir::StmtKind::ProcDeassign {
+            lhs: cx.map_ast_with_parent(AstNode::Expr(lhs), node_id),
+        },
+        ast::ForceStmt { ref lhs, ref rhs } => hir::StmtKind::Force {
+            lhs: cx.map_ast_with_parent(AstNode::Expr(lhs), node_id),
+            rhs: cx.map_ast_with_parent(AstNode::Expr(rhs), node_id),
+        },
+        ast::ReleaseStmt(ref lhs) => hir::StmtKind::Release {
+            lhs: cx.map_ast_with_parent(AstNode::Expr(lhs), node_id),
+        },
+        ast::DisableStmt(ref target) => match target.data {
+            ast::IdentExpr(name) => hir::StmtKind::Disable(name),
+            _ => {
+                cx.emit(
+                    DiagBuilder2::error("unsupported: disable of a hierarchical name")
+                        .span(stmt.human_span())
+                        .add_note("Only enclosing named blocks can be disabled"),
+                );
+                return Err(());
+            }
+        },
+        ast::DisableForkStmt => hir::StmtKind::DisableFork,
         _ => hir::StmtKind::Ast(stmt),
     })
 }
@@ -1426,6 +1719,56 @@ fn lower_event_expr<'gcx>(
     Ok(())
 }
 
+/// Ensure that an `always_ff` procedure is only sensitive to edges.
+fn check_always_ff_events<'gcx>(
+    cx: &impl Context<'gcx>,
+    prok: &'gcx ast::Procedure<'gcx>,
+) -> Result<()> {
+    let ctrl = match prok.stmt.kind {
+        ast::TimedStmt(ast::TimingControl::Event(ref ctrl), _) => ctrl,
+        _ => return Ok(()),
+    };
+    let mut level = vec![];
+    match ctrl.data {
+        ast::EventControlData::Implicit => level.push(ctrl.span),
+        ast::EventControlData::Expr(ref expr) => collect_level_events(expr, &mut level),
+    }
+    if level.is_empty() {
+        return Ok(());
+    }
+    for span in level {
+        cx.emit(
+            DiagBuilder2::error(format!(
+                "`always_ff` cannot be sensitive to level-triggered event `{}`",
+                span.extract()
+            ))
+            .span(span)
+            .add_note("Use `posedge` or `negedge` to make the event edge-triggered"),
+        );
+    }
+    Err(())
+}
+
+/// Collect the spans of all events in an event expression that are not
+/// edge-triggered.
+fn collect_level_events(expr: &ast::EventExpr, into: &mut Vec<Span>) {
+    match *expr {
+        ast::EventExpr::Edge {
+            span,
+            edge: ast::EdgeIdent::Implicit,
+            ..
+        } => into.push(span),
+        ast::EventExpr::Edge { .. } => (),
+        ast::EventExpr::Iff { ref expr, .. } => collect_level_events(expr, into),
+        ast::EventExpr::Or {
+            ref lhs, ref rhs, ..
+        } => {
+            collect_level_events(lhs, into);
+            collect_level_events(rhs, into);
+        }
+    }
+}
+
 /// Lower a list of genvar declarations.
 fn alloc_genvar_init<'gcx>(
     cx: &impl Context<'gcx>,
@@ -1566,7 +1909,8 @@ fn lower_package<'gcx>(
                 names.push((def.name, next_rib));
             }
             ast::ItemData::SubroutineDecl(ref decl) => {
-                warn!("ignoring unsupported subroutine `{}`", decl.prototype.name)
+                next_rib = cx.map_ast_with_parent(AstNode::SubroutineDecl(decl), next_rib);
+                names.push((decl.prototype.name, next_rib));
             }
             _ => {
                 cx.emit(
@@ -1669,6 +2013,51 @@ fn lower_call<'a>(
                     Err(())
                 }
             };
+            let map_scan = || match args {
+                [ast::CallArg {
+                    data:
+                        ast::CallArgData {
+                            expr: Some(ref source),
+                            ..
+                        },
+                    ..
+                }, ast::CallArg {
+                    data:
+                        ast::CallArgData {
+                            expr: Some(ref format),
+                            ..
+                        },
+                    ..
+                }, ref targets @ ..] => {
+                    let mut failed = false;
+                    for target in targets.iter().filter(|arg| arg.expr.is_none()) {
+                        cx.emit(
+                            DiagBuilder2::error(format!(
+                                "`{}` requires a variable for each scanned value",
+                                ident
+                            ))
+                            .span(target.span()),
+                        );
+                        failed = true;
+                    }
+                    if failed {
+                        Err(())
+                    } else {
+                        Ok((source, format, targets))
+                    }
+                }
+                _ => {
+                    cx.emit(
+                        DiagBuilder2::error(format!(
+                            "`{}` takes a source and a format string, followed by the scanned \
+                             variables",
+                            ident
+                        ))
+                        .span(expr.human_span()),
+                    );
+                    Err(())
+                }
+            };
             hir::ExprKind::Builtin(match &*ident.value.as_str() {
                 "clog2" => hir::BuiltinCall::Clog2(map_unary_id()?),
                 "signed" => hir::BuiltinCall::Signed(map_unary_id()?),
@@ -1683,6 +2072,14 @@ fn lower_call<'a>(
                 "high" => map_array_dim(hir::ArrayDim::High)?,
                 "increment" => map_array_dim(hir::ArrayDim::Increment)?,
                 "size" => map_array_dim(hir::ArrayDim::Size)?,
+                "sscanf" => {
+                    let (source, format, targets) = map_scan()?;
+                    hir::BuiltinCall::Sscanf(source, format, targets)
+                }
+                "fscanf" => {
+                    let (source, format, targets) = map_scan()?;
+                    hir::BuiltinCall::Fscanf(source, format, targets)
+                }
                 _ => {
                     cx.emit(
                         DiagBuilder2::warning(format!(
@@ -1695,10 +2092,28 @@ fn lower_call<'a>(
                 }
             })
         }
-        ast::IdentExpr(name) => {
+        ast::IdentExpr(..) | ast::ScopeExpr(..) => {
             // Resolve the function name and make sure it's something we can
             // call.
-            let def = cx.resolve_local_or_error(name, cx.scope_location(expr), false)?;
+            let (name, def) = match callee.data {
+                ast::IdentExpr(name) => {
+                    match cx.resolve_local(name.value, cx.scope_location(expr), false)? {
+                        Some(def) => (name, def),
+                        None => {
+                            cx.emit(
+                                DiagBuilder2::error(format!("unknown function `{}`", name.value))
+                                    .span(name.span),
+                            );
+                            return Err(());
+                        }
+                    }
+                }
+                ast::ScopeExpr(ref scope, name) => {
+                    let inside = lower_call_scope(cx, expr, scope)?;
+                    (name, cx.resolve_namespace_or_error(name, inside)?)
+                }
+                _ => unreachable!(),
+            };
             let target = match def.node {
                 DefNode::Ast(ast) => match ast.as_all() {
                     ast::AllNode::SubroutineDecl(x) => Some(x),
@@ -1734,6 +2149,49 @@ fn lower_call<'a>(
             // Package the call up.
             hir::ExprKind::FunctionCall(target, args)
         }
+        ast::MemberExpr {
+            expr: ref receiver,
+            name,
+        } => {
+            let method = match hir::BuiltinMethod::from_name(&*name.value.as_str()) {
+                Some(method) => method,
+                None => {
+                    cx.emit(
+                        DiagBuilder2::error(format!("unknown method `{}`", name.value))
+                            .span(name.span)
+                            .add_note(
+                                "Only the builtin array and queue methods `size`, `num`, \
+                                 `exists`, `delete`, `insert`, `push_front`, `push_back`, \
+                                 `pop_front`, and `pop_back` are supported",
+                            ),
+                    );
+                    return Err(());
+                }
+            };
+            let mut mapped_args = vec![];
+            for arg in args {
+                match arg.expr {
+                    Some(ref arg_expr) if arg.name.is_none() => {
+                        mapped_args.push(cx.map_ast_with_parent(AstNode::Expr(arg_expr), expr.id()))
+                    }
+                    _ => {
+                        cx.emit(
+                            DiagBuilder2::error(format!(
+                                "method `{}` only accepts positional arguments",
+                                method
+                            ))
+                            .span(arg.span()),
+                        );
+                        return Err(());
+                    }
+                }
+            }
+            hir::ExprKind::MethodCall {
+                receiver: cx.map_ast_with_parent(AstNode::Expr(receiver), expr.id()),
+                method: Spanned::new(method, name.span),
+                args: mapped_args,
+            }
+        }
         _ => {
             error!("{:#?}", callee);
             cx.emit(
@@ -1747,3 +2205,37 @@ fn lower_call<'a>(
         }
     })
 }
+
+/// Resolve the scope of a qualified function call, e.g. the `pkg` in
+/// `pkg::foo()`.
+fn lower_call_scope<'a>(
+    cx: &impl Context<'a>,
+    expr: &'a ast::Expr<'a>,
+    scope: &'a ast::Expr<'a>,
+) -> Result<&'a dyn ScopedNode<'a>> {
+    let name = match scope.data {
+        ast::IdentExpr(name) => name,
+        _ => {
+            cx.emit(
+                DiagBuilder2::error(format!("`{}` is not a package", scope.span().extract()))
+                    .span(scope.span()),
+            );
+            return Err(());
+        }
+    };
+    let def = cx.resolve_local_or_error(name, cx.scope_location(expr), true)?;
+    let inside = match def.node {
+        DefNode::Ast(node) => node.as_all().get_scoped_node(),
+        _ => None,
+    };
+    match inside {
+        Some(x) => Ok(x),
+        None => {
+            cx.emit(
+                DiagBuilder2::error(format!("name `{}` does not refer to a package", name))
+                    .span(name.span),
+            );
+            Err(())
+        }
+    }
+}
@@ -9,6 +9,7 @@ use crate::crate_prelude::*;
 use crate::mir::WalkVisitor as _;
 use std::{collections::BTreeSet, sync::Arc};
 
+pub(crate) mod analysis;
 pub(crate) mod lowering;
 mod nodes;
 mod visit;
@@ -30,6 +31,7 @@ make_arenas!(
         type_params: TypeParam,
         value_params: ValueParam,
         var_decls: VarDecl,
+        net_decls: NetDecl,
         procs: Proc,
         stmts: Stmt<'hir>,
         event_exprs: EventExpr,
@@ -84,13 +86,15 @@ pub enum AccessedNode {
     Regular(NodeId),
     /// An interface signal.
     Intf(NodeId, NodeId),
+    /// A declaration in one iteration of a generate loop.
+    Gen(NodeId, isize, NodeId),
 }
 
 impl AccessedNode {
     /// Get the ID of the accessed node, dropping any context information.
     pub fn id(&self) -> NodeId {
         match *self {
-            Self::Regular(id) | Self::Intf(_, id) => id,
+            Self::Regular(id) | Self::Intf(_, id) | Self::Gen(_, _, id) => id,
         }
     }
 }
@@ -146,6 +150,14 @@ where
                 }
                 true
             }
+            mir::LvalueKind::GenSignal { gen, index, decl }
+                if self.is_binding_interesting(decl) =>
+            {
+                self.table
+                    .written
+                    .insert(AccessedNode::Gen(gen, index, decl));
+                false
+            }
             _ => true,
         }
     }
@@ -166,6 +178,12 @@ where
                 }
                 true
             }
+            mir::RvalueKind::GenSignal { gen, index, decl }
+                if self.is_binding_interesting(decl) =>
+            {
+                self.table.read.insert(AccessedNode::Gen(gen, index, decl));
+                false
+            }
             _ => true,
         }
     }
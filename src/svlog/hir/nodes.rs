@@ -24,6 +24,7 @@ pub enum HirNode<'a> {
     TypeParam(&'a TypeParam),
     ValueParam(&'a ValueParam),
     VarDecl(&'a VarDecl),
+    NetDecl(&'a NetDecl),
     Proc(&'a Proc),
     Stmt(&'a Stmt<'a>),
     EventExpr(&'a EventExpr),
@@ -35,6 +36,7 @@ pub enum HirNode<'a> {
     EnumVariant(&'a EnumVariant),
     SubroutinePort(&'a ast::SubroutinePort<'a>),
     CallArg(&'a ast::CallArg<'a>),
+    ForeachIndex(&'a ast::ForeachIndex<'a>),
 }
 
 impl<'hir> HasSpan for HirNode<'hir> {
@@ -51,6 +53,7 @@ impl<'hir> HasSpan for HirNode<'hir> {
             HirNode::TypeParam(x) => x.span(),
             HirNode::ValueParam(x) => x.span(),
             HirNode::VarDecl(x) => x.span(),
+            HirNode::NetDecl(x) => x.span(),
             HirNode::Proc(x) => x.span(),
             HirNode::Stmt(x) => x.span(),
             HirNode::EventExpr(x) => x.span(),
@@ -62,6 +65,7 @@ impl<'hir> HasSpan for HirNode<'hir> {
             HirNode::EnumVariant(x) => x.span(),
             HirNode::SubroutinePort(x) => x.span(),
             HirNode::CallArg(x) => x.span(),
+            HirNode::ForeachIndex(x) => x.span(),
         }
     }
 
@@ -78,6 +82,7 @@ impl<'hir> HasSpan for HirNode<'hir> {
             HirNode::TypeParam(x) => x.human_span(),
             HirNode::ValueParam(x) => x.human_span(),
             HirNode::VarDecl(x) => x.human_span(),
+            HirNode::NetDecl(x) => x.human_span(),
             HirNode::Proc(x) => x.human_span(),
             HirNode::Stmt(x) => x.human_span(),
             HirNode::EventExpr(x) => x.human_span(),
@@ -89,6 +94,7 @@ impl<'hir> HasSpan for HirNode<'hir> {
             HirNode::EnumVariant(x) => x.human_span(),
             HirNode::SubroutinePort(x) => x.human_span(),
             HirNode::CallArg(x) => x.human_span(),
+            HirNode::ForeachIndex(x) => x.human_span(),
         }
     }
 }
@@ -107,6 +113,7 @@ impl<'hir> HasDesc for HirNode<'hir> {
             HirNode::TypeParam(x) => x.desc(),
             HirNode::ValueParam(x) => x.desc(),
             HirNode::VarDecl(x) => x.desc(),
+            HirNode::NetDecl(x) => x.desc(),
             HirNode::Proc(x) => x.desc(),
             HirNode::Stmt(x) => x.desc(),
             HirNode::EventExpr(x) => x.desc(),
@@ -118,6 +125,7 @@ impl<'hir> HasDesc for HirNode<'hir> {
             HirNode::EnumVariant(x) => x.desc(),
             HirNode::SubroutinePort(..) => "subroutine port",
             HirNode::CallArg(..) => "call argument",
+            HirNode::ForeachIndex(..) => "index variable",
         }
     }
 
@@ -134,6 +142,7 @@ impl<'hir> HasDesc for HirNode<'hir> {
             HirNode::TypeParam(x) => x.desc_full(),
             HirNode::ValueParam(x) => x.desc_full(),
             HirNode::VarDecl(x) => x.desc_full(),
+            HirNode::NetDecl(x) => x.desc_full(),
             HirNode::Proc(x) => x.desc_full(),
             HirNode::Stmt(x) => x.desc_full(),
             HirNode::EventExpr(x) => x.desc_full(),
@@ -145,6 +154,7 @@ impl<'hir> HasDesc for HirNode<'hir> {
             HirNode::EnumVariant(x) => x.desc_full(),
             HirNode::SubroutinePort(x) => x.to_string(),
             HirNode::CallArg(x) => x.to_string(),
+            HirNode::ForeachIndex(x) => format!("index variable `{}`", x.name),
         }
     }
 }
@@ -519,8 +529,16 @@ pub enum TypeKind {
     ///
     /// Represented as `(inner_type, range_lhs, range_rhs)`.
     PackedArray(Box<TypeKind>, NodeId, NodeId),
+    /// A packed array given by its size, such as `bit [32]`.
+    ///
+    /// Represented as `(inner_type, size)`. Equivalent to `[size-1:0]`.
+    PackedArraySize(Box<TypeKind>, NodeId),
     /// A scope access such as `foo::bar`.
     Scope(NodeId, Spanned<Name>),
+    /// A type member access such as `foo.bar`.
+    ///
+    /// Represented as `(inner_type, member_name)`.
+    ScopeMember(NodeId, Spanned<Name>),
     /// An enum type.
     ///
     /// Each element in the vector refers to a `EnumVariant`. The optional field
@@ -546,7 +564,8 @@ impl HasDesc for TypeKind {
             TypeKind::Builtin(BuiltinType::Integer) => "integer type",
             TypeKind::Builtin(BuiltinType::LongInt) => "long int type",
             TypeKind::Struct(_) => "struct type",
-            TypeKind::PackedArray(..) => "packed array type",
+            TypeKind::PackedArray(..) | TypeKind::PackedArraySize(..) => "packed array type",
+            TypeKind::ScopeMember(..) => "type member",
             _ => "type",
         }
     }
@@ -554,6 +573,7 @@ impl HasDesc for TypeKind {
     fn desc_full(&self) -> String {
         match *self {
             TypeKind::Named(n) => format!("type `{}`", n.value),
+            TypeKind::ScopeMember(_, n) => format!("type member `{}`", n.value),
             _ => self.desc().into(),
         }
     }
@@ -607,6 +627,9 @@ impl HasDesc for Expr<'_> {
         match self.kind {
             ExprKind::IntConst { .. } => "integer constant",
             ExprKind::TimeConst(_) => "time constant",
+            ExprKind::RealConst(_) => "real constant",
+            ExprKind::NullLiteral => "null literal",
+            ExprKind::Unbounded => "unbounded range bound",
             ExprKind::Ident(_) => "identifier",
             _ => "expression",
         }
@@ -617,10 +640,12 @@ impl HasDesc for Expr<'_> {
         match self.kind {
             ExprKind::IntConst { value: ref k, .. } => format!("{} `{}`", self.desc(), k),
             ExprKind::TimeConst(ref k) => format!("{} `{}`", self.desc(), k),
+            ExprKind::RealConst(ref k) => format!("{} `{}`", self.desc(), k),
             ExprKind::Ident(n) => format!("`{}`", n.value),
             ExprKind::PositionalPattern(..) => format!("positional pattern"),
             ExprKind::NamedPattern(..) => format!("named pattern"),
             ExprKind::RepeatPattern(..) => format!("repeat pattern"),
+            ExprKind::EmptyQueue => format!("empty queue"),
             _ => format!("{} `{}`", self.desc(), self.span().extract()),
         }
     }
@@ -644,6 +669,8 @@ pub enum ExprKind<'a> {
     UnsizedConst(char),
     /// A time constant literal.
     TimeConst(BigRational),
+    /// A real constant literal such as `1.5` or `1.0e-9`.
+    RealConst(BigRational),
     /// A string constant literal.
     StringConst(Spanned<Name>),
     /// An identifier.
@@ -668,8 +695,21 @@ pub enum ExprKind<'a> {
     NamedPattern(Vec<(PatternMapping, NodeId)>),
     /// A repeat pattern such as `'{32{a, b, c}}`.
     RepeatPattern(NodeId, Vec<NodeId>),
+    /// An empty queue `{}`.
+    EmptyQueue,
+    /// The `null` literal.
+    NullLiteral,
+    /// The unbounded `$` used in queue indices such as `q[$]` and open ranges
+    /// such as `[4:$]`.
+    Unbounded,
     /// A concatenation such as `{a,b}` or `{4{a,b}}`.
     Concat(Option<NodeId>, Vec<NodeId>),
+    /// A streaming concatenation such as `{>>{a,b}}` or `{<<8{a,b}}`.
+    StreamConcat {
+        dir: ast::StreamDir,
+        slice_size: Option<NodeId>,
+        exprs: Vec<NodeId>,
+    },
     /// A cast `(ty, expr)` such as `foo'(bar)`.
     Cast(NodeId, NodeId),
     /// A sign cast such as `unsigned'(foo)`.
@@ -680,6 +720,12 @@ pub enum ExprKind<'a> {
     Inside(NodeId, Vec<Spanned<InsideRange>>),
     /// A function call such as `foo(a, b, c)`.
     FunctionCall(&'a ast::SubroutineDecl<'a>, &'a [ast::CallArg<'a>]),
+    /// A method call such as `q.push_back(x)`.
+    MethodCall {
+        receiver: NodeId,
+        method: Spanned<BuiltinMethod>,
+        args: Vec<NodeId>,
+    },
     /// An assignment.
     Assign {
         op: ast::AssignOp,
@@ -860,6 +906,12 @@ pub enum BuiltinCall<'a> {
     IsUnknown(&'a ast::Expr<'a>),
     /// A call to one of the array dimension functions.
     ArrayDim(ArrayDim, &'a ast::Expr<'a>, Option<&'a ast::Expr<'a>>),
+    /// A call to the `$sscanf(str, format, args...)` function. Contains the
+    /// scanned string, the format string, and the target arguments.
+    Sscanf(&'a ast::Expr<'a>, &'a ast::Expr<'a>, &'a [ast::CallArg<'a>]),
+    /// A call to the `$fscanf(fd, format, args...)` function. Contains the
+    /// file descriptor, the format string, and the target arguments.
+    Fscanf(&'a ast::Expr<'a>, &'a ast::Expr<'a>, &'a [ast::CallArg<'a>]),
 }
 
 /// The different builtin array dimension function calls that are supported.
@@ -879,7 +931,69 @@ pub enum ArrayDim {
     Size,
 }
 
-/// A variable or net declaration.
+/// The different builtin array and queue methods that are supported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuiltinMethod {
+    /// The `size()` method of dynamic arrays and queues.
+    Size,
+    /// The `num()` method of associative arrays.
+    Num,
+    /// The `exists(key)` method of associative arrays.
+    Exists,
+    /// The `delete()` method of arrays and queues.
+    Delete,
+    /// The `insert(index, item)` method of queues.
+    Insert,
+    /// The `push_front(item)` method of queues.
+    PushFront,
+    /// The `push_back(item)` method of queues.
+    PushBack,
+    /// The `pop_front()` method of queues.
+    PopFront,
+    /// The `pop_back()` method of queues.
+    PopBack,
+}
+
+impl BuiltinMethod {
+    /// Classify a method by its name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "size" => Self::Size,
+            "num" => Self::Num,
+            "exists" => Self::Exists,
+            "delete" => Self::Delete,
+            "insert" => Self::Insert,
+            "push_front" => Self::PushFront,
+            "push_back" => Self::PushBack,
+            "pop_front" => Self::PopFront,
+            "pop_back" => Self::PopBack,
+            _ => return None,
+        })
+    }
+
+    /// The name of the method as written in the source code.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Size => "size",
+            Self::Num => "num",
+            Self::Exists => "exists",
+            Self::Delete => "delete",
+            Self::Insert => "insert",
+            Self::PushFront => "push_front",
+            Self::PushBack => "push_back",
+            Self::PopFront => "pop_front",
+            Self::PopBack => "pop_back",
+        }
+    }
+}
+
+impl std::fmt::Display for BuiltinMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// A variable declaration.
 #[derive(Debug, PartialEq, Eq)]
 pub struct VarDecl {
     pub id: NodeId,
@@ -889,8 +1003,6 @@ pub struct VarDecl {
     pub ty: NodeId,
     /// Initial value
     pub init: Option<NodeId>,
-    /// Variable or net-specific data
-    pub kind: ast::VarKind,
 }
 
 impl HasSpan for VarDecl {
@@ -905,17 +1017,47 @@ impl HasSpan for VarDecl {
 
 impl HasDesc for VarDecl {
     fn desc(&self) -> &'static str {
-        match self.kind {
-            ast::VarKind::Var => "variable declaration",
-            ast::VarKind::Net { .. } => "net declaration",
-        }
+        "variable declaration"
     }
 
     fn desc_full(&self) -> String {
-        match self.kind {
-            ast::VarKind::Var => format!("variable `{}`", self.name.value),
-            ast::VarKind::Net { .. } => format!("net `{}`", self.name.value),
-        }
+        format!("variable `{}`", self.name.value)
+    }
+}
+
+/// A net declaration.
+#[derive(Debug, PartialEq, Eq)]
+pub struct NetDecl {
+    pub id: NodeId,
+    pub name: Spanned<Name>,
+    pub span: Span,
+    /// Net type, e.g. `wire` or `tri`
+    pub net_type: ast::NetType,
+    /// Whether the net is `vectored` or `scalared`
+    pub kind: ast::NetKind,
+    /// Data type
+    pub ty: NodeId,
+    /// Initial value
+    pub init: Option<NodeId>,
+}
+
+impl HasSpan for NetDecl {
+    fn span(&self) -> Span {
+        self.span
+    }
+
+    fn human_span(&self) -> Span {
+        self.name.span
+    }
+}
+
+impl HasDesc for NetDecl {
+    fn desc(&self) -> &'static str {
+        "net declaration"
+    }
+
+    fn desc_full(&self) -> String {
+        format!("net `{}`", self.name.value)
     }
 }
 
@@ -1024,10 +1166,90 @@ pub enum StmtKind<'a> {
         default: Option<NodeId>,
         kind: ast::CaseKind,
     },
+    /// An immediate assertion.
+    ///
+    /// ```text
+    /// assert (<cond>) [<pass_stmt>] [else <fail_stmt>]
+    /// ```
+    Assert {
+        kind: AssertKind,
+        cond: NodeId,
+        pass_stmt: Option<NodeId>,
+        fail_stmt: Option<NodeId>,
+    },
+    /// A wait statement.
+    ///
+    /// ```text
+    /// wait (<cond>) <stmt>
+    /// ```
+    Wait { cond: NodeId, stmt: NodeId },
+    /// A procedural continuous assignment.
+    ///
+    /// ```text
+    /// assign <lhs> = <rhs>
+    /// ```
+    ProcAssign { lhs: NodeId, rhs: NodeId },
+    /// A procedural continuous deassignment.
+    ///
+    /// ```text
+    /// deassign <lhs>
+    /// ```
+    ProcDeassign { lhs: NodeId },
+    /// A force statement.
+    ///
+    /// ```text
+    /// force <lhs> = <rhs>
+    /// ```
+    Force { lhs: NodeId, rhs: NodeId },
+    /// A release statement.
+    ///
+    /// ```text
+    /// release <lhs>
+    /// ```
+    Release { lhs: NodeId },
+    /// A wait fork statement.
+    ///
+    /// ```text
+    /// wait fork
+    /// ```
+    WaitFork,
+    /// A disable statement targeting an enclosing named block.
+    ///
+    /// ```text
+    /// disable <name>
+    /// ```
+    Disable(Spanned<Name>),
+    /// A disable fork statement.
+    ///
+    /// ```text
+    /// disable fork
+    /// ```
+    DisableFork,
+    /// A break statement.
+    Break,
+    /// A continue statement.
+    Continue,
+    /// A return statement.
+    ///
+    /// ```text
+    /// return [<expr>]
+    /// ```
+    Return(Option<NodeId>),
     /// A statement in the AST that requires no representational change.
     Ast(&'a ast::Stmt<'a>),
 }
 
+/// The different forms an immediate assertion can take.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AssertKind {
+    /// An `assert` statement.
+    Assert,
+    /// An `assume` statement.
+    Assume,
+    /// A `cover` statement.
+    Cover,
+}
+
 /// The different forms an assignment can take.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum AssignKind {
@@ -1040,7 +1262,7 @@ pub enum AssignKind {
 }
 
 /// The different forms a loop can take.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum LoopKind {
     /// A `forever` loop.
     Forever,
@@ -1052,6 +1274,8 @@ pub enum LoopKind {
     Do(NodeId),
     //// A `for (<init>; <cond>; <step>)` loop.
     For(NodeId, NodeId, NodeId),
+    /// A `foreach (<array>[<indices>])` loop.
+    Foreach(NodeId, Vec<NodeId>),
 }
 
 /// The different forms of timing control that can be applied to a statement.
@@ -1063,6 +1287,9 @@ pub enum TimingControl {
     ImplicitEvent,
     /// A statement triggered by an explicit event expression.
     ExplicitEvent(NodeId),
+    /// A statement delayed by a number of clock cycles. Contains an
+    /// expression that evaluates to the number of cycles.
+    CycleDelay(NodeId),
 }
 
 /// An event expression.
@@ -1131,6 +1358,20 @@ pub enum GenKind {
         cond: NodeId,
         step: NodeId,
         body: ModuleBlock,
+        /// The optional label of the generate block.
+        label: Option<Spanned<Name>>,
+    },
+    /// A case-generate statement.
+    Case {
+        expr: NodeId,
+        ways: Vec<(Vec<NodeId>, ModuleBlock)>,
+        default: Option<ModuleBlock>,
+    },
+    /// A named generate block that appears directly in a module or generate
+    /// region.
+    Block {
+        body: ModuleBlock,
+        label: Spanned<Name>,
     },
 }
 
@@ -1199,6 +1440,8 @@ pub struct Assign {
     pub span: Span,
     pub lhs: NodeId,
     pub rhs: NodeId,
+    /// The delay with which the right-hand side is applied, if any.
+    pub delay: Option<NodeId>,
 }
 
 impl HasSpan for Assign {
@@ -36,6 +36,7 @@ pub trait Visitor<'a>: Sized {
             HirNode::EventExpr(x) => self.visit_event_expr(x),
             HirNode::Typedef(x) => self.visit_typedef(x),
             HirNode::VarDecl(x) => self.visit_var_decl(x),
+            HirNode::NetDecl(x) => self.visit_net_decl(x),
             HirNode::Assign(x) => self.visit_assign(x),
             HirNode::IntPort(x) => self.visit_int_port(x),
             HirNode::ExtPort(x) => self.visit_ext_port(x),
@@ -85,6 +86,10 @@ pub trait Visitor<'a>: Sized {
         walk_var_decl(self, decl);
     }
 
+    fn visit_net_decl(&mut self, decl: &'a NetDecl) {
+        walk_net_decl(self, decl);
+    }
+
     fn visit_assign(&mut self, assign: &'a Assign) {
         walk_assign(self, assign);
     }
@@ -151,16 +156,31 @@ pub fn walk_proc<'a>(visitor: &mut impl Visitor<'a>, prok: &'a Proc) {
 pub fn walk_stmt<'a>(visitor: &mut impl Visitor<'a>, stmt: &'a Stmt) {
     #[allow(unreachable_patterns)]
     match stmt.kind {
-        StmtKind::Null => (),
+        StmtKind::Null
+        | StmtKind::WaitFork
+        | StmtKind::Disable(..)
+        | StmtKind::DisableFork
+        | StmtKind::Break
+        | StmtKind::Continue => (),
+        StmtKind::Return(expr) => {
+            if let Some(expr) = expr {
+                visitor.visit_node_with_id(expr, false);
+            }
+        }
         StmtKind::Block(ref stmts) => {
             for &id in stmts {
                 visitor.visit_node_with_id(id, false);
             }
         }
-        StmtKind::Assign { lhs, rhs, .. } => {
+        StmtKind::Assign { lhs, rhs, .. }
+        | StmtKind::ProcAssign { lhs, rhs }
+        | StmtKind::Force { lhs, rhs } => {
             visitor.visit_node_with_id(lhs, true);
             visitor.visit_node_with_id(rhs, false);
         }
+        StmtKind::ProcDeassign { lhs } | StmtKind::Release { lhs } => {
+            visitor.visit_node_with_id(lhs, true);
+        }
         StmtKind::Timed { ref control, stmt } => {
             visitor.visit_timing_control(control);
             visitor.visit_node_with_id(stmt, false);
@@ -177,8 +197,8 @@ pub fn walk_stmt<'a>(visitor: &mut impl Visitor<'a>, stmt: &'a Stmt) {
                 visitor.visit_node_with_id(else_stmt, false);
             }
         }
-        StmtKind::Loop { kind, body } => {
-            match kind {
+        StmtKind::Loop { ref kind, body } => {
+            match *kind {
                 LoopKind::Forever => (),
                 LoopKind::Repeat(id) | LoopKind::While(id) | LoopKind::Do(id) => {
                     visitor.visit_node_with_id(id, false);
@@ -188,6 +208,12 @@ pub fn walk_stmt<'a>(visitor: &mut impl Visitor<'a>, stmt: &'a Stmt) {
                     visitor.visit_node_with_id(cond, false);
                     visitor.visit_node_with_id(step, false);
                 }
+                LoopKind::Foreach(array, ref indices) => {
+                    visitor.visit_node_with_id(array, false);
+                    for &index in indices {
+                        visitor.visit_node_with_id(index, false);
+                    }
+                }
             }
             visitor.visit_node_with_id(body, false);
         }
@@ -213,6 +239,24 @@ pub fn walk_stmt<'a>(visitor: &mut impl Visitor<'a>, stmt: &'a Stmt) {
                 visitor.visit_node_with_id(default, false);
             }
         }
+        StmtKind::Assert {
+            cond,
+            pass_stmt,
+            fail_stmt,
+            ..
+        } => {
+            visitor.visit_node_with_id(cond, false);
+            if let Some(pass_stmt) = pass_stmt {
+                visitor.visit_node_with_id(pass_stmt, false);
+            }
+            if let Some(fail_stmt) = fail_stmt {
+                visitor.visit_node_with_id(fail_stmt, false);
+            }
+        }
+        StmtKind::Wait { cond, stmt } => {
+            visitor.visit_node_with_id(cond, false);
+            visitor.visit_node_with_id(stmt, false);
+        }
         StmtKind::Ast(ast) => visitor.visit_node_with_id(ast.id(), false),
     }
 }
@@ -224,7 +268,11 @@ pub fn walk_expr<'a>(visitor: &mut impl Visitor<'a>, expr: &'a Expr, lvalue: boo
         | ExprKind::IntConst { .. }
         | ExprKind::UnsizedConst(_)
         | ExprKind::TimeConst(_)
-        | ExprKind::StringConst(_) => (),
+        | ExprKind::RealConst(_)
+        | ExprKind::StringConst(_)
+        | ExprKind::EmptyQueue
+        | ExprKind::NullLiteral
+        | ExprKind::Unbounded => (),
         ExprKind::Ident(x) => {
             visitor.visit_ident(x);
         }
@@ -270,6 +318,14 @@ pub fn walk_expr<'a>(visitor: &mut impl Visitor<'a>, expr: &'a Expr, lvalue: boo
         ExprKind::Builtin(BuiltinCall::Bits(arg)) => {
             visitor.visit_node_with_id(arg.id(), false);
         }
+        ExprKind::Builtin(BuiltinCall::Sscanf(source, format, targets))
+        | ExprKind::Builtin(BuiltinCall::Fscanf(source, format, targets)) => {
+            visitor.visit_node_with_id(source.id(), false);
+            visitor.visit_node_with_id(format.id(), false);
+            for target in targets.iter().flat_map(|arg| arg.expr.as_ref()) {
+                visitor.visit_node_with_id(target.id(), true);
+            }
+        }
         ExprKind::Ternary(cond, true_expr, false_expr) => {
             visitor.visit_node_with_id(cond, false);
             visitor.visit_node_with_id(true_expr, lvalue);
@@ -307,6 +363,18 @@ pub fn walk_expr<'a>(visitor: &mut impl Visitor<'a>, expr: &'a Expr, lvalue: boo
                 visitor.visit_node_with_id(expr, lvalue);
             }
         }
+        ExprKind::StreamConcat {
+            slice_size,
+            ref exprs,
+            ..
+        } => {
+            if let Some(slice_size) = slice_size {
+                visitor.visit_node_with_id(slice_size, false);
+            }
+            for &expr in exprs {
+                visitor.visit_node_with_id(expr, lvalue);
+            }
+        }
         ExprKind::Cast(ty, expr) => {
             visitor.visit_node_with_id(ty, false);
             visitor.visit_node_with_id(expr, false);
@@ -337,6 +405,14 @@ pub fn walk_expr<'a>(visitor: &mut impl Visitor<'a>, expr: &'a Expr, lvalue: boo
                 }
             }
         }
+        ExprKind::MethodCall {
+            receiver, ref args, ..
+        } => {
+            visitor.visit_node_with_id(receiver, false);
+            for &arg in args {
+                visitor.visit_node_with_id(arg, false);
+            }
+        }
         ExprKind::Assign { lhs, rhs, .. } => {
             visitor.visit_node_with_id(lhs.id, true);
             visitor.visit_node_with_id(rhs.id, false);
@@ -351,6 +427,7 @@ pub fn walk_timing_control<'a>(visitor: &mut impl Visitor<'a>, ctrl: &'a TimingC
         TimingControl::Delay(id) => visitor.visit_node_with_id(id, false),
         TimingControl::ImplicitEvent => (),
         TimingControl::ExplicitEvent(id) => visitor.visit_node_with_id(id, false),
+        TimingControl::CycleDelay(id) => visitor.visit_node_with_id(id, false),
     }
 }
 
@@ -382,10 +459,21 @@ pub fn walk_var_decl<'a>(visitor: &mut impl Visitor<'a>, decl: &'a VarDecl) {
     }
 }
 
+/// Walk the contents of a net declaration.
+pub fn walk_net_decl<'a>(visitor: &mut impl Visitor<'a>, decl: &'a NetDecl) {
+    visitor.visit_node_with_id(decl.ty, false);
+    if let Some(init) = decl.init {
+        visitor.visit_node_with_id(init, false);
+    }
+}
+
 /// Walk the contents of an assignment.
 pub fn walk_assign<'a>(visitor: &mut impl Visitor<'a>, assign: &'a Assign) {
     visitor.visit_node_with_id(assign.lhs, true);
     visitor.visit_node_with_id(assign.rhs, false);
+    if let Some(delay) = assign.delay {
+        visitor.visit_node_with_id(delay, false);
+    }
 }
 
 /// Walk the contents of an internal port.
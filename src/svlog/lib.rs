@@ -136,12 +136,19 @@ pub use moore_common::{
 /// A general result returned by the queries.
 pub type Result<T> = std::result::Result<T, ()>;
 
+/// The source text of the built-in `std` package.
+///
+/// Frontends parse this alongside the input files such that `std::`-qualified
+/// names can be resolved.
+pub const STD_PACKAGE: &str = include_str!("std.sv");
+
 pub use crate::{
     codegen::CodeGenerator,
     context::*,
     inst_details::{InstDetails, InstTargetDetails, InstVerbosityVisitor},
     param_env::{
         IntoNodeEnvId, NodeEnvId, ParamEnv, ParamEnvBinding, ParamEnvData, ParamEnvSource,
+        ParamInfo, ParamInfoKind,
     },
     port_mapping::{PortMapping, PortMappingSource},
     // resolver::*,
@@ -265,6 +272,7 @@ mod queries {
     use crate::{
         call_mapping::*,
         func_args::*,
+        hir::analysis::*,
         hir::lowering::*,
         hir::{accessed_nodes, AccessTable, HirNode},
         inst_details::*,
@@ -31,14 +31,17 @@ pub(crate) fn mir_assignment_from_procedural<'a>(
         // `a = b`
         hir::AssignKind::Block(ast::AssignOp::Identity)
         | hir::AssignKind::Nonblock
-        | hir::AssignKind::NonblockDelay(_) => Assignment {
-            id: origin,
-            env,
-            span,
-            ty: lhs_mir_lv.ty,
-            lhs: lhs_mir_lv,
-            rhs: rhs_mir,
-        },
+        | hir::AssignKind::NonblockDelay(_) => {
+            check_width(cx, lhs_mir_lv, rhs, rhs_mir, env);
+            Assignment {
+                id: origin,
+                env,
+                span,
+                ty: lhs_mir_lv.ty,
+                lhs: lhs_mir_lv,
+                rhs: rhs_mir,
+            }
+        }
         // `a (+= -= *= /= %= &= |= ^= <<= >>= <<<= >>>=) b`
         hir::AssignKind::Block(op) => {
             let builder = lower::rvalue::Builder {
@@ -117,6 +120,7 @@ pub(crate) fn mir_assignment_from_concurrent<'a>(
 ) -> &'a mir::Assignment<'a> {
     let lhs_mir = cx.mir_lvalue(assign.lhs, env);
     let rhs_mir = cx.mir_rvalue(assign.rhs, env);
+    check_width(cx, lhs_mir, assign.rhs, rhs_mir, env);
     cx.arena().alloc_mir_assignment(Assignment {
         id: assign.id,
         env,
@@ -127,6 +131,53 @@ pub(crate) fn mir_assignment_from_concurrent<'a>(
     })
 }
 
+/// Warn about an assignment that implicitly extends or truncates its
+/// right-hand side.
+///
+/// Only active if the `warn_width` session option is set. Constants are
+/// adjusted to the width of the left-hand side without a warning.
+fn check_width<'a>(
+    cx: &impl Context<'a>,
+    lhs: &'a Lvalue<'a>,
+    rhs: NodeId,
+    rhs_mir: &'a Rvalue<'a>,
+    env: ParamEnv,
+) {
+    if !cx.sess().opts.warn_width || lhs.is_error() || rhs_mir.is_error() || rhs_mir.is_const() {
+        return;
+    }
+    let rhs_ty = match cx.cast_type(rhs, env) {
+        Some(x) => x.init,
+        None => return,
+    };
+    if !lhs.ty.coalesces_to_llhd_scalar() || !rhs_ty.coalesces_to_llhd_scalar() {
+        return;
+    }
+    let (lhs_width, rhs_width) = match (lhs.ty.get_bit_size(), rhs_ty.get_bit_size()) {
+        (Some(l), Some(r)) => (l, r),
+        _ => return,
+    };
+    let what = if rhs_width > lhs_width {
+        "truncates"
+    } else if rhs_width < lhs_width {
+        "extends"
+    } else {
+        return;
+    };
+    cx.emit(
+        DiagBuilder2::warning(format!(
+            "assignment implicitly {} `{}` from {} to {} bits",
+            what,
+            rhs_mir.span.extract(),
+            rhs_width,
+            lhs_width
+        ))
+        .span(rhs_mir.span)
+        .add_note(format!("`{}` is of type `{}`:", lhs.span.extract(), lhs.ty))
+        .span(lhs.span),
+    );
+}
+
 /// Simplify an MIR assignment to potentially multiple simple MIR assignments.
 ///
 /// This eliminates assignments to compound `Lvalue` objects, for example
@@ -4,6 +4,7 @@
 
 use crate::crate_prelude::*;
 use crate::{
+    ast_map::AstNode,
     hir::HirNode,
     mir::{
         lower,
@@ -132,7 +133,20 @@ fn lower_expr_inner<'a>(
             let binding = cx.resolve_node(expr_id, env)?;
             return match cx.hir_of(binding)? {
                 HirNode::GenvarDecl(decl) => Ok(builder.build(ty, LvalueKind::Genvar(decl.id))),
+                HirNode::VarDecl(decl) if is_const_var(cx, decl.id) => {
+                    cx.emit(
+                        DiagBuilder2::error(format!(
+                            "constant `{}` cannot be assigned to",
+                            decl.name
+                        ))
+                        .span(span)
+                        .add_note(format!("`{}` was declared `const` here:", decl.name))
+                        .span(decl.human_span()),
+                    );
+                    Err(())
+                }
                 HirNode::VarDecl(decl) => Ok(builder.build(ty, LvalueKind::Var(decl.id))),
+                HirNode::NetDecl(decl) => Ok(builder.build(ty, LvalueKind::Var(decl.id))),
                 HirNode::IntPort(port) if ty.resolve_full().core.get_interface().is_some() => {
                     Ok(builder.build(ty, LvalueKind::Intf(port.id)))
                 }
@@ -160,6 +174,16 @@ fn lower_expr_inner<'a>(
 
             // Lower the indexee and make sure it can be indexed into.
             let target = cx.mir_lvalue(target, env);
+            if let Some(ty::Dim::Unpacked(ty::UnpackedDim::Assoc(_))) = target.ty.outermost_dim() {
+                cx.emit(
+                    DiagBuilder2::error(format!(
+                        "unsupported: access into associative array of type `{}`",
+                        target.ty
+                    ))
+                    .span(span),
+                );
+                return Err(());
+            }
             assert_span!(
                 target.ty.dims().next().is_some(),
                 target.span,
@@ -192,10 +216,51 @@ fn lower_expr_inner<'a>(
         }
 
         hir::ExprKind::Field(target, name) => {
+            // References into an iteration of a generate loop.
+            if let Some(member) = cx.resolve_genblk_member(expr_id, env)? {
+                return match cx.hir_of(member.decl)? {
+                    HirNode::VarDecl(_) | HirNode::NetDecl(_) => Ok(builder.build(
+                        ty,
+                        LvalueKind::GenSignal {
+                            gen: member.gen,
+                            index: member.index,
+                            decl: member.decl,
+                        },
+                    )),
+                    x => {
+                        cx.emit(
+                            DiagBuilder2::error(format!(
+                                "{} cannot be used as the target of an assignment",
+                                x.desc_full()
+                            ))
+                            .span(span),
+                        );
+                        Err(())
+                    }
+                };
+            }
+            // References into a named generate block.
+            if let Some(decl) = cx.resolve_block_member(expr_id, env)? {
+                return match cx.hir_of(decl)? {
+                    HirNode::VarDecl(_) | HirNode::NetDecl(_) => {
+                        Ok(builder.build(ty, LvalueKind::Var(decl)))
+                    }
+                    x => {
+                        cx.emit(
+                            DiagBuilder2::error(format!(
+                                "{} cannot be used as the target of an assignment",
+                                x.desc_full()
+                            ))
+                            .span(span),
+                        );
+                        Err(())
+                    }
+                };
+            }
             let target_ty = cx.self_determined_type(target, env);
             let value = cx.mir_lvalue(target, env);
             if let Some(intf) = target_ty.and_then(|ty| ty.get_interface()) {
-                let def = cx.resolve_hierarchical_or_error(name, intf.ast)?;
+                let def = cx.resolve_intf_member_or_error(name, intf.ast, intf.modport)?;
                 // Distinguish `intf.modport` and `intf.signal`.
                 if def.node.as_all().is_modport_name() {
                     return Ok(builder.build(ty, value.kind.clone()));
@@ -266,6 +331,14 @@ fn lower_expr_inner<'a>(
     Err(())
 }
 
+/// Check whether a variable was declared `const`.
+fn is_const_var<'a>(cx: &impl Context<'a>, decl_id: NodeId) -> bool {
+    match cx.ast_of(decl_id) {
+        Ok(AstNode::VarDecl(_, decl, _)) => decl.konst,
+        _ => false,
+    }
+}
+
 /// Generate the nodes necessary for a cast operation.
 fn lower_cast<'a>(
     builder: &Builder<'_, impl Context<'a>>,
@@ -14,7 +14,10 @@ use crate::{
     ParamEnv,
 };
 use num::{BigInt, BigRational, One, Signed, ToPrimitive, Zero};
-use std::{cmp::max, collections::HashMap};
+use std::{
+    cmp::{max, min},
+    collections::HashMap,
+};
 
 /// An internal builder for rvalue lowering.
 pub struct Builder<'a, C> {
@@ -161,6 +164,10 @@ fn lower_expr_inner<'gcx>(
             bug_span!(span, cx, "unsized const with weird '{}' char", c)
         }
         hir::ExprKind::TimeConst(ref k) => Ok(builder.constant(value::make_time(k.clone()))),
+        hir::ExprKind::RealConst(..) => {
+            cx.emit(DiagBuilder2::error(format!("unsupported: {}", hir.desc_full())).span(span));
+            Err(())
+        }
         hir::ExprKind::StringConst(string) => Ok(builder.constant(value::make_int(
             // TODO: This could use `value::make_string` to build a string
             // value, and then resort to the conversion function there to map
@@ -230,6 +237,17 @@ fn lower_expr_inner<'gcx>(
             // Since we currently don't emit logic types, this is always zero.
             Ok(builder.constant(value::make_int(ty, num::zero())))
         }
+        hir::ExprKind::Builtin(hir::BuiltinCall::Sscanf(..))
+        | hir::ExprKind::Builtin(hir::BuiltinCall::Fscanf(..)) => {
+            // Scanning formatted input requires a runtime, which the lowering
+            // to hardware does not provide.
+            let name = match hir.kind {
+                hir::ExprKind::Builtin(hir::BuiltinCall::Sscanf(..)) => "sscanf",
+                _ => "fscanf",
+            };
+            cx.emit(DiagBuilder2::error(format!("unsupported: `${}`", name)).span(span));
+            Err(())
+        }
         hir::ExprKind::Builtin(hir::BuiltinCall::ArrayDim(func, arg, dim)) => {
             // Decide which dimension to inspect.
             let dim = match dim {
@@ -293,6 +311,7 @@ fn lower_expr_inner<'gcx>(
             let binding = builder.cx.resolve_node(expr_id, env)?;
             match builder.cx.hir_of(binding)? {
                 HirNode::VarDecl(decl) => Ok(builder.build(ty, RvalueKind::Var(decl.id))),
+                HirNode::NetDecl(decl) => Ok(builder.build(ty, RvalueKind::Var(decl.id))),
                 HirNode::IntPort(port) if ty.resolve_full().core.get_interface().is_some() => {
                     Ok(builder.build(ty, RvalueKind::Intf(port.id)))
                 }
@@ -305,6 +324,7 @@ fn lower_expr_inner<'gcx>(
                     Ok(builder.build(ty, RvalueKind::Const(k)))
                 }
                 HirNode::SubroutinePort(port) => Ok(builder.build(ty, RvalueKind::Arg(port.id))),
+                HirNode::ForeachIndex(index) => Ok(builder.build(ty, RvalueKind::Var(index.id()))),
                 x => {
                     builder.cx.emit(
                         DiagBuilder2::error(format!(
@@ -327,6 +347,18 @@ fn lower_expr_inner<'gcx>(
             let false_value = cx.mir_rvalue(false_value, env);
             assert_type!(true_value.ty, ty, true_value.span, builder.cx);
             assert_type!(false_value.ty, ty, false_value.span, builder.cx);
+
+            // If the condition is a known constant, pick the corresponding
+            // value right away.
+            if cond.is_const() && !cond.is_error() {
+                match cx.const_mir_rvalue(Ref(cond)).kind {
+                    ValueKind::Int(ref v, ref special, _) if special.none() => {
+                        return Ok(if v.is_zero() { false_value } else { true_value });
+                    }
+                    _ => (),
+                }
+            }
+
             Ok(builder.build(
                 ty,
                 RvalueKind::Ternary {
@@ -341,6 +373,40 @@ fn lower_expr_inner<'gcx>(
         | hir::ExprKind::RepeatPattern(..)
         | hir::ExprKind::NamedPattern(..) => Ok(lower_pattern(&builder, hir, ty)),
 
+        hir::ExprKind::EmptyQueue => {
+            match ty.outermost_dim() {
+                Some(ty::Dim::Unpacked(ty::UnpackedDim::Queue(_))) => {
+                    cx.emit(DiagBuilder2::error("unsupported: queues").span(span));
+                }
+                _ => {
+                    cx.emit(
+                        DiagBuilder2::error(format!(
+                            "empty queue `{{}}` cannot be used as `{}`",
+                            ty
+                        ))
+                        .span(span)
+                        .add_note("`{}` can only be assigned to queues"),
+                    );
+                }
+            }
+            Err(())
+        }
+
+        hir::ExprKind::NullLiteral => {
+            cx.emit(
+                DiagBuilder2::error(format!("unsupported: `null` used as `{}`", ty)).span(span),
+            );
+            Err(())
+        }
+
+        hir::ExprKind::Unbounded => {
+            cx.emit(
+                DiagBuilder2::error("unsupported: unbounded `$` outside of an `inside` range")
+                    .span(span),
+            );
+            Err(())
+        }
+
         hir::ExprKind::Concat(repeat, ref exprs) => {
             // Compute the SBVT for each expression and lower it to MIR,
             // implicitly casting to the SBVT.
@@ -383,12 +449,95 @@ fn lower_expr_inner<'gcx>(
             Ok(repeat)
         }
 
+        hir::ExprKind::StreamConcat {
+            dir,
+            slice_size,
+            ref exprs,
+        } => {
+            // Lower the streamed expressions, as for a regular concatenation.
+            let exprs = exprs
+                .iter()
+                .map(|&expr| {
+                    let value = builder.cx.mir_rvalue(expr, env);
+                    assert_span!(value.ty.coalesces_to_llhd_scalar(), value.span, builder.cx);
+                    value
+                })
+                .collect::<Vec<_>>();
+
+            // Compute the result type of the concatenation.
+            let final_ty = builder.cx.need_self_determined_type(hir.id, env);
+            if final_ty.is_error() {
+                return Err(());
+            }
+            let domain = final_ty.domain();
+            let width = final_ty.get_bit_size().unwrap();
+            let concat_ty = SbvType::new(domain, ty::Sign::Unsigned, width).to_unpacked(builder.cx);
+            let concat = builder.build(concat_ty, RvalueKind::Concat(exprs));
+
+            // Streaming left-to-right yields the concatenation itself, and the
+            // slice size has no effect.
+            if dir == ast::StreamDir::Right {
+                return Ok(concat);
+            }
+
+            // Streaming right-to-left slices the concatenation into blocks,
+            // starting at the right-most bit, and reverses their order. The
+            // left-most block may be shorter than the slice size.
+            let slice_size = match slice_size {
+                Some(slice_size_id) => {
+                    let size = builder.cx.constant_int_value_of(slice_size_id, env)?;
+                    match size.to_usize() {
+                        Some(size) if size > 0 => size,
+                        _ => {
+                            builder.cx.emit(
+                                DiagBuilder2::error(format!(
+                                    "slice size of streaming concatenation must be positive, \
+                                     but is {}",
+                                    size
+                                ))
+                                .span(builder.cx.span(slice_size_id)),
+                            );
+                            return Err(());
+                        }
+                    }
+                }
+                None => 1,
+            };
+            let blocks = (0..width)
+                .step_by(slice_size)
+                .map(|base| {
+                    let length = min(slice_size, width - base);
+                    builder.build(
+                        SbvType::new(domain, ty::Sign::Unsigned, length).to_unpacked(builder.cx),
+                        RvalueKind::Index {
+                            value: concat,
+                            base: builder.constant_u32(base as u32),
+                            length,
+                        },
+                    )
+                })
+                .collect();
+            Ok(builder.build(concat_ty, RvalueKind::Concat(blocks)))
+        }
+
         hir::ExprKind::Index(target, mode) => {
             let (base, length) = compute_indexing(cx, builder.expr, env, mode)?;
 
             // Cast the target to a simple bit vector type if needed.
             let target = cx.mir_rvalue(target, env);
 
+            // Associative arrays have no representation in the MIR yet.
+            if let Some(ty::Dim::Unpacked(ty::UnpackedDim::Assoc(_))) = target.ty.outermost_dim() {
+                cx.emit(
+                    DiagBuilder2::error(format!(
+                        "unsupported: access into associative array of type `{}`",
+                        target.ty
+                    ))
+                    .span(span),
+                );
+                return Err(());
+            }
+
             // Make sure we can actually index here.
             assert_span!(
                 target.ty.dims().next().is_some(),
@@ -416,10 +565,51 @@ fn lower_expr_inner<'gcx>(
         }
 
         hir::ExprKind::Field(target, name) => {
+            // References into an iteration of a generate loop.
+            if let Some(member) = cx.resolve_genblk_member(expr_id, env)? {
+                return match cx.hir_of(member.decl)? {
+                    HirNode::VarDecl(_) | HirNode::NetDecl(_) => Ok(builder.build(
+                        ty,
+                        RvalueKind::GenSignal {
+                            gen: member.gen,
+                            index: member.index,
+                            decl: member.decl,
+                        },
+                    )),
+                    x => {
+                        cx.emit(
+                            DiagBuilder2::error(format!(
+                                "{} cannot be used in an expression",
+                                x.desc_full()
+                            ))
+                            .span(span),
+                        );
+                        Err(())
+                    }
+                };
+            }
+            // References into a named generate block.
+            if let Some(decl) = cx.resolve_block_member(expr_id, env)? {
+                return match cx.hir_of(decl)? {
+                    HirNode::VarDecl(_) | HirNode::NetDecl(_) => {
+                        Ok(builder.build(ty, RvalueKind::Var(decl)))
+                    }
+                    x => {
+                        cx.emit(
+                            DiagBuilder2::error(format!(
+                                "{} cannot be used in an expression",
+                                x.desc_full()
+                            ))
+                            .span(span),
+                        );
+                        Err(())
+                    }
+                };
+            }
             let target_ty = cx.self_determined_type(target, env);
             let value = cx.mir_rvalue(target, env);
             if let Some(intf) = target_ty.and_then(|ty| ty.get_interface()) {
-                let def = cx.resolve_hierarchical_or_error(name, intf.ast)?;
+                let def = cx.resolve_intf_member_or_error(name, intf.ast, intf.modport)?;
                 // Distinguish `intf.modport` and `intf.signal`.
                 if def.node.as_all().is_modport_name() {
                     Ok(builder.build(ty, value.kind.clone()))
@@ -470,33 +660,45 @@ fn lower_expr_inner<'gcx>(
                         )
                     }
                     hir::InsideRange::Range(lo, hi) => {
-                        // Check if the LHS is within [lo:hi], inclusive.
-                        let lo_rv = cx.mir_rvalue(lo, env);
-                        let hi_rv = cx.mir_rvalue(hi, env);
-                        let lo_chk = make_int_comparison(
-                            &builder.with(lo),
-                            out_ty,
-                            comp_ty,
-                            IntCompOp::Geq,
-                            lhs,
-                            lo_rv,
-                        );
-                        let hi_chk = make_int_comparison(
-                            &builder.with(hi),
-                            out_ty,
-                            comp_ty,
-                            IntCompOp::Leq,
-                            lhs,
-                            hi_rv,
-                        );
-                        make_binary_bitwise(
-                            builder,
-                            ty,
-                            BinaryBitwiseOp::And,
-                            false,
-                            lo_chk,
-                            hi_chk,
-                        )
+                        // Check if the LHS is within [lo:hi], inclusive. An
+                        // unbounded `$` leaves that side of the range open.
+                        let lo_chk = match is_unbounded(cx, lo) {
+                            true => None,
+                            false => Some(make_int_comparison(
+                                &builder.with(lo),
+                                out_ty,
+                                comp_ty,
+                                IntCompOp::Geq,
+                                lhs,
+                                cx.mir_rvalue(lo, env),
+                            )),
+                        };
+                        let hi_chk = match is_unbounded(cx, hi) {
+                            true => None,
+                            false => Some(make_int_comparison(
+                                &builder.with(hi),
+                                out_ty,
+                                comp_ty,
+                                IntCompOp::Leq,
+                                lhs,
+                                cx.mir_rvalue(hi, env),
+                            )),
+                        };
+                        match (lo_chk, hi_chk) {
+                            (Some(lo_chk), Some(hi_chk)) => make_binary_bitwise(
+                                builder,
+                                ty,
+                                BinaryBitwiseOp::And,
+                                false,
+                                lo_chk,
+                                hi_chk,
+                            ),
+                            (Some(chk), None) | (None, Some(chk)) => chk,
+                            (None, None) => builder.build(
+                                ty,
+                                RvalueKind::Const(cx.intern_value(value::make_int(ty, One::one()))),
+                            ),
+                        }
                     }
                 };
                 check = make_binary_bitwise(builder, ty, BinaryBitwiseOp::Or, false, check, arg);
@@ -511,12 +713,28 @@ fn lower_expr_inner<'gcx>(
 
         hir::ExprKind::Assign { op, lhs, rhs } => Ok(lower_assign(&builder, ty, op, lhs, rhs)),
 
+        hir::ExprKind::MethodCall { method, .. } => {
+            cx.emit(
+                DiagBuilder2::error(format!("unsupported: `{}()` method call", method.value))
+                    .span(span),
+            );
+            Err(())
+        }
+
         hir::ExprKind::Ast(expr) => {
             bug_span!(span, cx, "unsupported raw AST expr {:#?}", expr)
         }
     }
 }
 
+/// Check whether an expression is the unbounded `$`.
+fn is_unbounded<'gcx>(cx: &impl Context<'gcx>, expr_id: NodeId) -> bool {
+    match cx.hir_of(expr_id) {
+        Ok(HirNode::Expr(expr)) => expr.kind == hir::ExprKind::Unbounded,
+        _ => false,
+    }
+}
+
 /// Compute the base and length of an indexing operation.
 ///
 /// Determine the index of the LSB and the width of the selection. Note that
@@ -996,6 +1214,10 @@ fn lower_pattern<'a>(
     if ty.coalesces_to_llhd_scalar() {
         if values.len() == 1 {
             values[0]
+        } else if values.iter().all(|&value| std::ptr::eq(value, values[0])) {
+            // Patterns such as `'{4{x}}` repeat the same value, which maps to
+            // a replication rather than a concatenation.
+            builder.build(ty, RvalueKind::Repeat(values.len(), values[0]))
         } else {
             builder.build(ty, RvalueKind::Concat(values))
         }
@@ -85,6 +85,9 @@ impl<'a> Print for Lvalue<'a> {
             LvalueKind::IntfSignal(arg, sig) => {
                 write!(inner, "IntfSignal({}, {:?})", ctx.print(outer, arg), sig)?
             }
+            LvalueKind::GenSignal { gen, index, decl } => {
+                write!(inner, "GenSignal({:?}[{}], {:?})", gen, index, decl)?
+            }
             LvalueKind::Index {
                 value,
                 base,
@@ -147,6 +150,12 @@ pub enum LvalueKind<'a> {
     Intf(NodeId),
     /// A reference to an interface's signal.
     IntfSignal(&'a Lvalue<'a>, NodeId),
+    /// A reference to a declaration in one iteration of a generate loop.
+    GenSignal {
+        gen: NodeId,
+        index: isize,
+        decl: NodeId,
+    },
     /// A bit- or part-select.
     Index {
         value: &'a Lvalue<'a>,
@@ -183,6 +183,9 @@ impl<'a> Print for Rvalue<'a> {
             RvalueKind::IntfSignal(arg, sig) => {
                 write!(inner, "IntfSignal({}, {:?})", ctx.print(outer, arg), sig)?
             }
+            RvalueKind::GenSignal { gen, index, decl } => {
+                write!(inner, "GenSignal({:?}[{}], {:?})", gen, index, decl)?
+            }
             RvalueKind::Index {
                 value,
                 base,
@@ -379,6 +382,12 @@ pub enum RvalueKind<'a> {
     Intf(NodeId),
     /// A reference to a locally instantiated interface signal.
     IntfSignal(&'a Rvalue<'a>, NodeId),
+    /// A reference to a declaration in one iteration of a generate loop.
+    GenSignal {
+        gen: NodeId,
+        index: isize,
+        decl: NodeId,
+    },
     /// A bit- or part-select.
     Index {
         value: &'a Rvalue<'a>,
@@ -479,6 +488,7 @@ impl<'a> RvalueKind<'a> {
             RvalueKind::Arg(_) => false,
             RvalueKind::Intf(_) => false,
             RvalueKind::IntfSignal(..) => false,
+            RvalueKind::GenSignal { .. } => false,
             RvalueKind::Index { .. } => false, // TODO(fschuiki): reactivate once impl
             // RvalueKind::Index { value, base, .. } => value.is_const() && base.is_const(),
             RvalueKind::Ternary {
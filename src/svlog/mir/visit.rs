@@ -24,6 +24,7 @@ pub trait WalkVisitor<'a> {
 
 impl<'a> WalkVisitor<'a> for bool {}
 impl<'a> WalkVisitor<'a> for usize {}
+impl<'a> WalkVisitor<'a> for isize {}
 impl<'a> WalkVisitor<'a> for NodeId {}
 impl<'a> WalkVisitor<'a> for ParamEnv {}
 impl<'a> WalkVisitor<'a> for Span {}
@@ -5,7 +5,7 @@
 use crate::{
     ast_map::AstNode,
     crate_prelude::*,
-    hir::{NamedParam, PosParam},
+    hir::{HirNode, NamedParam, PosParam},
     ty::UnpackedType,
     value::Value,
 };
@@ -276,6 +276,7 @@ fn param_env_from_instance<'a>(
             (Some(i), n) => i.env(n),
             _ => continue,
         };
+        check_param_assignment_kind(cx, param_id, assign_id.0)?;
         match cx.ast_of(param_id)? {
             AstNode::TypeParam(..) => {
                 cx.set_lowering_hint(assign_id.0, hir::Hint::Type);
@@ -298,3 +299,99 @@ fn param_env_from_instance<'a>(
     cx.add_param_env_context(env, node.id());
     Ok(env)
 }
+
+/// Check that a parameter assignment provides a type for a type parameter, and
+/// an expression for a value parameter.
+fn check_param_assignment_kind<'a>(
+    cx: &impl Context<'a>,
+    param_id: NodeId,
+    assign_id: NodeId,
+) -> Result<()> {
+    let assign = match cx.ast_of(assign_id)? {
+        AstNode::TypeOrExpr(x) => cx.disamb_type_or_expr(Ref(x))?,
+        _ => return Ok(()),
+    };
+    let (name, span, expected) = match (cx.ast_of(param_id)?, assign) {
+        (AstNode::TypeParam(_, p), ast::TypeOrExpr::Expr(expr)) => (p.name, expr.span, "a type"),
+        (AstNode::ValueParam(_, p), ast::TypeOrExpr::Type(ty)) => (p.name, ty.span, "a value"),
+        _ => return Ok(()),
+    };
+    cx.emit(
+        DiagBuilder2::error(format!(
+            "parameter `{}` requires {}, but `{}` was given",
+            name.value,
+            expected,
+            span.extract()
+        ))
+        .span(span)
+        .add_note(format!("`{}` was declared here:", name.value))
+        .span(name.span),
+    );
+    Err(())
+}
+
+/// A parameter of a module, as seen by an instantiation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParamInfo {
+    /// Node ID of the parameter.
+    pub id: NodeId,
+    /// Name of the parameter.
+    pub name: Spanned<Name>,
+    /// Whether this is a type or a value parameter.
+    pub kind: ParamInfoKind,
+    /// The default type or value of the parameter, if any.
+    pub default: Option<NodeId>,
+}
+
+/// The kind of a parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParamInfoKind {
+    /// A `parameter type`.
+    Type,
+    /// A value `parameter`.
+    Value,
+}
+
+impl std::fmt::Display for ParamInfoKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ParamInfoKind::Type => write!(f, "type"),
+            ParamInfoKind::Value => write!(f, "value"),
+        }
+    }
+}
+
+/// List the parameters of a module.
+///
+/// Returns the parameters that can be overridden by an instantiation, in the
+/// order in which they are declared. Local parameters are omitted.
+#[moore_derive::query]
+pub(crate) fn module_params<'a>(
+    cx: &impl Context<'a>,
+    module_id: NodeId,
+) -> Result<Vec<ParamInfo>> {
+    let module = match cx.hir_of(module_id)? {
+        HirNode::Module(x) => x,
+        _ => unreachable!(),
+    };
+    let mut params = vec![];
+    for &param_id in module.params {
+        let info = match cx.hir_of(param_id)? {
+            HirNode::TypeParam(p) if !p.local => ParamInfo {
+                id: param_id,
+                name: p.name,
+                kind: ParamInfoKind::Type,
+                default: p.default,
+            },
+            HirNode::ValueParam(p) if !p.local => ParamInfo {
+                id: param_id,
+                name: p.name,
+                kind: ParamInfoKind::Value,
+                default: p.default,
+            },
+            _ => continue,
+        };
+        params.push(info);
+    }
+    Ok(params)
+}
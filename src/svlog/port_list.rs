@@ -21,7 +21,7 @@
 // mapping business).
 
 use crate::crate_prelude::*;
-use crate::{ast_map::AstNode, common::arenas::Alloc};
+use crate::{ast_map::AstNode, common::arenas::Alloc, hir::HirNode};
 use std::{
     borrow::Cow,
     collections::HashMap,
@@ -82,6 +82,10 @@ pub struct IntPortData<'a> {
     /// checked against `ty`.
     // TODO: Make this an AST reference.
     pub matching: Option<(NodeId, ())>,
+    /// Optional redundant packed and unpacked dimensions of the net or
+    /// variable declaration of a non-ANSI port, which must agree with the
+    /// port's dimensions.
+    pub matching_dims: Option<(Span, &'a [ast::TypeDim<'a>], &'a [ast::TypeDim<'a>])>,
     /// Optional default value assigned to the port if left unconnected.
     // TODO: Make this an AST reference.
     pub default: Option<NodeId>,
@@ -375,6 +379,9 @@ pub(crate) fn canonicalize_ports<'a>(
                 ty,
                 unpacked_dims: port.unpacked_dims,
                 matching: None,
+                matching_dims: port
+                    .match_ty
+                    .map(|(span, _, packed, unpacked)| (span, packed, unpacked)),
                 default,
             })
         };
@@ -403,6 +410,60 @@ pub(crate) fn canonicalize_ports<'a>(
     cx.gcx().arena.alloc_port_list(list)
 }
 
+/// The total bit widths of a module's ports, grouped by direction.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PortWidths {
+    /// The summed width of all input ports.
+    pub input: usize,
+    /// The summed width of all output ports.
+    pub output: usize,
+    /// The summed width of all bidirectional (`inout` and `ref`) ports.
+    pub inout: usize,
+}
+
+/// Determine the total bit widths of a module's ports.
+///
+/// Sums up the widths of the module's internal ports, separately for inputs,
+/// outputs, and bidirectional ports. Emits an error for ports that do not
+/// have a fixed bit size.
+#[moore_derive::query]
+pub(crate) fn module_port_widths<'a>(
+    cx: &impl Context<'a>,
+    module_id: NodeId,
+    env: ParamEnv,
+) -> Result<PortWidths> {
+    let module = match cx.hir_of(module_id)? {
+        HirNode::Module(x) => x,
+        _ => unreachable!(),
+    };
+    let mut widths = PortWidths::default();
+    for port in &module.ports_new.int {
+        let ty = cx.type_of_int_port(Ref(port), env);
+        if ty.is_error() {
+            return Err(());
+        }
+        let size = match ty.get_bit_size() {
+            Some(size) => size,
+            None => {
+                cx.emit(
+                    DiagBuilder2::error(format!(
+                        "port `{}` of type `{}` has no fixed bit size",
+                        port.name, ty
+                    ))
+                    .span(port.span),
+                );
+                return Err(());
+            }
+        };
+        match port.dir {
+            ast::PortDir::Input => widths.input += size,
+            ast::PortDir::Output => widths.output += size,
+            ast::PortDir::Inout | ast::PortDir::Ref => widths.inout += size,
+        }
+    }
+    Ok(widths)
+}
+
 /// Lower the ANSI ports of a node.
 fn lower_node_ports_ansi<'a>(
     cx: &impl Context<'a>,
@@ -867,6 +928,7 @@ fn lower_node_ports_nonansi<'a>(
 
         // Merge the type.
         port.match_ty = Some((
+            add_span,
             match (&port.ty.data, &add_ty.data) {
                 (_, ast::ImplicitType) => None,
                 (ast::ImplicitType, _) => {
@@ -878,6 +940,11 @@ fn lower_node_ports_nonansi<'a>(
             add_packed,
             add_unpacked,
         ));
+
+        // Merge the packed and unpacked dimensions. Whether they agree can
+        // only be checked once their bounds are evaluated in typeck.
+        port.packed_dims = merge_port_dims(port.packed_dims, add_packed);
+        port.unpacked_dims = merge_port_dims(port.unpacked_dims, add_unpacked);
     }
 
     // As a fourth step, go through the ports themselves and pair them up with
@@ -1096,6 +1163,22 @@ fn lower_port_ref<'gcx>(
     }
 }
 
+/// Merge the dimensions of a non-ANSI port declaration with the dimensions of
+/// the corresponding net or variable declaration.
+///
+/// Dimensions provided by only one of the two declarations carry over to the
+/// port. If both provide dimensions, the port's take precedence.
+fn merge_port_dims<'a>(
+    port_dims: &'a [ast::TypeDim<'a>],
+    add_dims: &'a [ast::TypeDim<'a>],
+) -> &'a [ast::TypeDim<'a>] {
+    if port_dims.is_empty() {
+        add_dims
+    } else {
+        port_dims
+    }
+}
+
 #[derive(Debug)]
 struct PartialPort<'a> {
     span: Span,
@@ -1121,6 +1204,7 @@ struct PartialPort<'a> {
     /// Redundant type information which must be checked against the non-ANSI
     /// port later.
     match_ty: Option<(
+        Span,
         Option<&'a ast::TypeKind<'a>>,
         &'a [ast::TypeDim<'a>],
         &'a [ast::TypeDim<'a>],
@@ -14,6 +14,7 @@ use crate::{
     port_list::{self, AsPortedNode},
     ParamEnv,
 };
+use num::ToPrimitive;
 use std::{
     collections::HashMap,
     hash::{Hash, Hasher},
@@ -138,7 +139,10 @@ pub(crate) fn local_rib<'a>(cx: &impl Context<'a>, node_id: NodeId) -> Result<&'
                     import.pkg,
                     cx.ast_of(pkg_id)?.desc_full()
                 );
-                let resolved = cx.resolve_downwards_or_error(name, pkg_id)?;
+                let resolved = match cx.package_symbol(pkg_id, name.value) {
+                    Some(id) => id,
+                    None => cx.resolve_downwards_or_error(name, pkg_id)?,
+                };
                 let rib = cx.local_rib(resolved)?;
                 Some(RibKind::Import(Box::new(rib.clone())))
             } else {
@@ -201,7 +205,9 @@ pub(crate) fn local_rib<'a>(cx: &impl Context<'a>, node_id: NodeId) -> Result<&'
 fn local_rib_kind_for_type<'gcx>(cx: &impl Context<'gcx>, kind: &hir::TypeKind) -> Option<RibKind> {
     trace!("creating local rib for type {:#?}", kind);
     match kind {
-        hir::TypeKind::PackedArray(inner, ..) => local_rib_kind_for_type(cx, inner.as_ref()),
+        hir::TypeKind::PackedArray(inner, ..) | hir::TypeKind::PackedArraySize(inner, _) => {
+            local_rib_kind_for_type(cx, inner.as_ref())
+        }
         hir::TypeKind::Enum(ref variants, _) => Some(RibKind::Enum(
             variants
                 .iter()
@@ -294,6 +300,36 @@ pub(crate) fn resolve_downwards<'a>(
     Ok(cx.resolve_namespace(name, node).map(|def| def.node.id()))
 }
 
+/// Look up a symbol declared in a package.
+///
+/// Searches the typedefs, parameters, and declarations of the lowered package
+/// for one with the given name. Returns `None` if no such symbol exists.
+#[moore_derive::query]
+pub(crate) fn package_symbol<'a>(
+    cx: &impl Context<'a>,
+    pkg_id: NodeId,
+    name: Name,
+) -> Option<NodeId> {
+    let pkg = match cx.hir_of(pkg_id).ok()? {
+        HirNode::Package(x) => x,
+        _ => return None,
+    };
+    if let Some(&(_, id)) = pkg.names.iter().find(|(n, _)| n.value == name) {
+        return Some(id);
+    }
+    pkg.params
+        .iter()
+        .chain(pkg.decls.iter())
+        .cloned()
+        .find(|&id| match cx.hir_of(id) {
+            Ok(HirNode::ValueParam(x)) => x.name.value == name,
+            Ok(HirNode::TypeParam(x)) => x.name.value == name,
+            Ok(HirNode::VarDecl(x)) => x.name.value == name,
+            Ok(HirNode::NetDecl(x)) => x.name.value == name,
+            _ => false,
+        })
+}
+
 /// Resolve a node to its target.
 #[moore_derive::query]
 pub(crate) fn resolve_node<'a>(
@@ -317,6 +353,24 @@ pub(crate) fn resolve_node<'a>(
                 let within = cx.resolve_node(scope_id, env)?;
                 return cx.resolve_downwards_or_error(name, within);
             }
+            hir::TypeKind::ScopeMember(ty_id, name) => {
+                let strukt = struct_type_of(cx, cx.resolve_node(ty_id, env)?, env)?;
+                let def = cx.struct_def(strukt)?;
+                match def.fields.iter().find(|f| f.name.value == name.value) {
+                    Some(field) => return Ok(field.field),
+                    None => {
+                        cx.emit(
+                            DiagBuilder2::error(format!(
+                                "`{}` is not a member of `{}`",
+                                name,
+                                cx.span(ty_id).extract()
+                            ))
+                            .span(name.span),
+                        );
+                        return Err(());
+                    }
+                }
+            }
             _ => (),
         },
         HirNode::IntPort(port) if port.data.is_none() => {
@@ -334,6 +388,27 @@ pub(crate) fn resolve_node<'a>(
     Err(())
 }
 
+/// Find the struct type that a typedef or struct member refers to.
+///
+/// Follows typedefs and member types until a type other than a named one is
+/// found.
+fn struct_type_of<'a>(cx: &impl Context<'a>, node_id: NodeId, env: ParamEnv) -> Result<NodeId> {
+    let mut node_id = node_id;
+    loop {
+        node_id = match cx.hir_of(node_id)? {
+            HirNode::Typedef(def) => def.ty,
+            HirNode::VarDecl(decl) => decl.ty,
+            HirNode::Type(ty) => match ty.kind {
+                hir::TypeKind::Named(..)
+                | hir::TypeKind::Scope(..)
+                | hir::TypeKind::ScopeMember(..) => cx.resolve_node(node_id, env)?,
+                _ => return Ok(node_id),
+            },
+            _ => return Ok(node_id),
+        };
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct StructDef {
     pub packed: bool,
@@ -431,6 +506,177 @@ pub(crate) fn resolve_field_access<'a>(
     }
 }
 
+/// A declaration in one iteration of a generate loop.
+///
+/// This is what a hierarchical reference such as `g[1].x` refers to, where `g`
+/// is the label of a generate loop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GenblkMember {
+    /// The generate loop.
+    pub gen: NodeId,
+    /// The value of the genvar in the referenced iteration.
+    pub index: isize,
+    /// The declaration inside the loop body.
+    pub decl: NodeId,
+    /// The parameter environment of the referenced iteration.
+    pub env: ParamEnv,
+}
+
+/// Check whether an expression refers to the block of a generate loop.
+pub(crate) fn is_genblk_ref<'a>(cx: &impl Context<'a>, node_id: NodeId, env: ParamEnv) -> bool {
+    match cx.hir_of(node_id) {
+        Ok(HirNode::Expr(hir::Expr {
+            kind: hir::ExprKind::Ident(..),
+            ..
+        })) => (),
+        _ => return false,
+    }
+    let binding = match cx.resolve_node(node_id, env) {
+        Ok(x) => x,
+        Err(()) => return false,
+    };
+    match cx.ast_for_id(binding).as_all() {
+        ast::AllNode::GenerateBlock(block) => block
+            .get_parent()
+            .map(|p| p.as_all().is_generate_for())
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+/// Resolve a hierarchical reference into an iteration of a generate loop.
+///
+/// Handles field accesses such as `g[i].x`, where `g` is the label of a
+/// generate loop and `i` a constant expression. Returns `None` if the field
+/// access is not of this form.
+#[moore_derive::query]
+pub(crate) fn resolve_genblk_member<'a>(
+    cx: &impl Context<'a>,
+    node_id: NodeId,
+    env: ParamEnv,
+) -> Result<Option<GenblkMember>> {
+    let (target, name) = match cx.hir_of(node_id)? {
+        HirNode::Expr(hir::Expr {
+            kind: hir::ExprKind::Field(target, name),
+            ..
+        }) => (*target, *name),
+        _ => return Ok(None),
+    };
+    let (block_ref, index) = match cx.hir_of(target)? {
+        HirNode::Expr(hir::Expr {
+            kind: hir::ExprKind::Index(block_ref, hir::IndexMode::One(index)),
+            ..
+        }) => (*block_ref, *index),
+        _ => return Ok(None),
+    };
+    if !is_genblk_ref(cx, block_ref, env) {
+        return Ok(None);
+    }
+
+    // Find the loop and the block it generates.
+    let block = match cx.ast_for_id(cx.resolve_node(block_ref, env)?).as_all() {
+        ast::AllNode::GenerateBlock(x) => x,
+        _ => unreachable!(),
+    };
+    let gen = block.get_parent().unwrap().id();
+
+    // Evaluate the index and find the declaration in the loop body.
+    let index_value = cx.constant_int_value_of(index, env)?;
+    let index = match index_value.to_isize() {
+        Some(x) => x,
+        None => {
+            cx.emit(
+                DiagBuilder2::error(format!(
+                    "generate block index `{}` is too large",
+                    index_value
+                ))
+                .span(cx.span(index)),
+            );
+            return Err(());
+        }
+    };
+    let decl = cx.resolve_hierarchical_or_error(name, block)?.node.id();
+    let env = cx.genblk_iteration_env(gen, index, env)?;
+    Ok(Some(GenblkMember {
+        gen,
+        index,
+        decl,
+        env,
+    }))
+}
+
+/// Resolve a hierarchical reference into a named generate block.
+///
+/// Handles field accesses such as `blk.x`, where `blk` is the label of a
+/// generate block that appears directly in a module or generate region.
+/// Returns `None` if the field access is not of this form.
+#[moore_derive::query]
+pub(crate) fn resolve_block_member<'a>(
+    cx: &impl Context<'a>,
+    node_id: NodeId,
+    env: ParamEnv,
+) -> Result<Option<NodeId>> {
+    let (target, name) = match cx.hir_of(node_id)? {
+        HirNode::Expr(hir::Expr {
+            kind: hir::ExprKind::Field(target, name),
+            ..
+        }) => (*target, *name),
+        _ => return Ok(None),
+    };
+    match cx.hir_of(target)? {
+        HirNode::Expr(hir::Expr {
+            kind: hir::ExprKind::Ident(..),
+            ..
+        }) => (),
+        _ => return Ok(None),
+    }
+    let block = match cx.ast_for_id(cx.resolve_node(target, env)?).as_all() {
+        ast::AllNode::GenerateBlock(x) => x,
+        _ => return Ok(None),
+    };
+    if let Some(parent) = block.get_parent() {
+        let parent = parent.as_all();
+        if parent.is_generate_for() || parent.is_generate_if() || parent.is_generate_case() {
+            return Ok(None);
+        }
+    }
+    Ok(Some(
+        cx.resolve_hierarchical_or_error(name, block)?.node.id(),
+    ))
+}
+
+/// Determine the parameter environment of an iteration of a generate loop.
+///
+/// Binds the loop's genvar to `index` in `env`.
+#[moore_derive::query]
+pub(crate) fn genblk_iteration_env<'a>(
+    cx: &impl Context<'a>,
+    gen: NodeId,
+    index: isize,
+    env: ParamEnv,
+) -> Result<ParamEnv> {
+    let init = match cx.hir_of(gen)? {
+        HirNode::Gen(hir::Gen {
+            kind: hir::GenKind::For { init, .. },
+            ..
+        }) => *init.last().unwrap(),
+        _ => unreachable!(),
+    };
+    let genvar = match cx.hir_of(init)? {
+        HirNode::GenvarDecl(_) => init,
+        HirNode::Stmt(hir::Stmt {
+            kind: hir::StmtKind::Assign { lhs, .. },
+            ..
+        }) => cx.resolve_node(*lhs, env)?,
+        _ => unreachable!(),
+    };
+    let ty = cx.type_of(genvar, env)?;
+    let value = cx.intern_value(value::make_int(ty, index.into()));
+    let mut env_data = cx.param_env_data(env).clone();
+    env_data.set_value(genvar, value);
+    Ok(cx.intern_param_env(env_data))
+}
+
 /// Determine the scope generated by a node.
 pub fn generated_scope_id<'gcx>(
     cx: &impl Context<'gcx>,
@@ -996,6 +1242,17 @@ impl<'a, C: Context<'a>> ast::Visitor<'a> for ScopeGenerator<'a, '_, C> {
 
     fn pre_visit_generate_for(&mut self, node: &'a ast::GenerateFor<'a>) -> bool {
         self.add_subscope(node);
+        // The label of the loop's block is also visible in the enclosing
+        // scope, such that `g[i].x` may refer to a loop iteration.
+        if let Some(name) = node.block.label {
+            self.add_def(Def {
+                node: DefNode::Ast(&node.block),
+                name,
+                vis: DefVis::LOCAL | DefVis::HIERARCHICAL,
+                may_override: false,
+                ordered: false,
+            });
+        }
         false
     }
 
@@ -1322,6 +1579,71 @@ pub(crate) fn resolve_hierarchical_or_error<'a>(
     }
 }
 
+/// Resolve a member of an interface accessed through an optional modport or
+/// emit an error.
+///
+/// If a modport is given, only the signals listed in it are accessible. Either
+/// returns `Ok` if a node was found, or `Err` after emitting a diagnostic error
+/// message.
+#[moore_derive::query]
+pub(crate) fn resolve_intf_member_or_error<'a>(
+    cx: &impl Context<'a>,
+    name: Spanned<Name>,
+    intf: &'a ast::Interface<'a>,
+    modport: Option<&'a ast::ModportName<'a>>,
+) -> Result<&'a Def<'a>> {
+    let modport = match modport {
+        Some(x) => x,
+        None => return cx.resolve_hierarchical_or_error(name, intf),
+    };
+    let mut ports = modport.ports.iter().flat_map(|port| match port.data {
+        ast::ModportPortData::Simple { ref port, .. } => port.iter(),
+    });
+
+    // Expression ports expose an expression of the interface under a new
+    // name, which we cannot resolve yet.
+    if let Some(port) = ports
+        .clone()
+        .find(|port| port.expr.is_some() && port.name.value == name.value)
+    {
+        cx.emit(
+            DiagBuilder2::error(format!(
+                "unsupported: modport expression port `{}`",
+                name.value
+            ))
+            .span(name.span)
+            .add_note(format!(
+                "`{}` is declared as an expression port of modport `{}` here:",
+                name.value, modport.name
+            ))
+            .span(port.name.span),
+        );
+        return Err(());
+    }
+
+    let def = cx.resolve_hierarchical_or_error(name, intf)?;
+    if def.node.as_all().is_modport_name() {
+        return Ok(def);
+    }
+    let listed = ports.any(|port| port.expr.is_none() && port.name.value == name.value);
+    if !listed {
+        cx.emit(
+            DiagBuilder2::error(format!(
+                "`{}` is not accessible through modport `{}`",
+                name.value, modport.name
+            ))
+            .span(name.span)
+            .add_note(format!(
+                "Modport `{}` does not list `{}` among its ports:",
+                modport.name, name.value
+            ))
+            .span(modport.span()),
+        );
+        return Err(());
+    }
+    Ok(def)
+}
+
 /// Resolve an import to the scope it imports.
 ///
 /// This function emits a diagnostic if the target of the import has no scope.
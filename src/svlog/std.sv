@@ -0,0 +1,13 @@
+// Copyright (c) 2016-2021 Fabian Schuiki
+
+// The built-in `std` package (IEEE 1800-2017 section 26.7). It is implicitly
+// available to every design. Most of its contents are classes and randomization
+// facilities which are not supported yet, so only stubs are provided here.
+
+package std;
+    // Scope randomization (IEEE 1800-2017 section 18.12). Randomization is not
+    // supported; the call always reports failure.
+    function automatic int randomize();
+        return 0;
+    endfunction
+endpackage
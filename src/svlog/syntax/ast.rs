@@ -682,6 +682,7 @@ pub enum Item<'a> {
     GenerateFor(#[forward] GenerateFor<'a>),
     GenerateIf(#[forward] GenerateIf<'a>),
     GenerateCase(#[forward] GenerateCase<'a>),
+    GenerateBlock(#[forward] GenerateBlock<'a>),
     Assertion(Assertion<'a>),
     NetDecl(NetDecl<'a>),
     VarDecl(#[forward] VarDecl<'a>),
@@ -1132,6 +1133,17 @@ pub enum ProcedureKind {
     Final,
 }
 
+impl ProcedureKind {
+    /// Check if this procedure executes repeatedly, as opposed to only once
+    /// like `initial` and `final` procedures.
+    pub fn is_repeating(&self) -> bool {
+        match self {
+            Self::Initial | Self::Final => false,
+            Self::Always | Self::AlwaysComb | Self::AlwaysLatch | Self::AlwaysFf => true,
+        }
+    }
+}
+
 /// A statement.
 #[moore_derive::node]
 #[indefinite("statement")]
@@ -1190,7 +1202,17 @@ pub enum StmtKind<'a> {
     WaitExprStmt(Expr<'a>, Box<Stmt<'a>>),
     WaitForkStmt,
     DisableForkStmt,
-    DisableStmt(Name),
+    DisableStmt(Expr<'a>),
+    ProcAssignStmt {
+        lhs: Expr<'a>,
+        rhs: Expr<'a>,
+    },
+    ProcDeassignStmt(Expr<'a>),
+    ForceStmt {
+        lhs: Expr<'a>,
+        rhs: Expr<'a>,
+    },
+    ReleaseStmt(Expr<'a>),
 }
 
 impl<'a> Stmt<'a> {
@@ -1267,14 +1289,17 @@ pub enum EventControlData<'a> {
 
 #[moore_derive::visit]
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct CycleDelay {}
+pub struct CycleDelay<'a> {
+    pub span: Span,
+    pub expr: Expr<'a>,
+}
 
 #[moore_derive::visit]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TimingControl<'a> {
     Delay(DelayControl<'a>),
     Event(EventControl<'a>),
-    Cycle(CycleDelay),
+    Cycle(CycleDelay<'a>),
 }
 
 #[moore_derive::visit]
@@ -1390,6 +1415,7 @@ pub enum Expr<'a> {
     ArrayNewExpr(Box<Expr<'a>>, Option<Box<Expr<'a>>>),
     EmptyQueueExpr,
     StreamConcatExpr {
+        dir: StreamDir,
         slice: Option<StreamConcatSlice<'a>>,
         exprs: Vec<StreamExpr<'a>>,
     },
@@ -1557,6 +1583,15 @@ pub struct CallArg<'a> {
     pub expr: Option<Expr<'a>>,
 }
 
+#[moore_derive::visit]
+#[derive(Debug, Clone, PartialEq, Eq, Copy, Hash)]
+pub enum StreamDir {
+    /// `>>`, which streams blocks in left-to-right order.
+    Right,
+    /// `<<`, which streams blocks in right-to-left order.
+    Left,
+}
+
 #[moore_derive::visit]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum StreamConcatSlice<'a> {
@@ -2258,8 +2293,17 @@ pub struct GenerateIf<'a> {
 #[moore_derive::node]
 #[indefinite("case-generate statement")]
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct GenerateCase {
-    // TODO
+pub struct GenerateCase<'a> {
+    pub expr: Expr<'a>,
+    pub items: Vec<GenerateCaseItem<'a>>,
+}
+
+/// An item of a `case` generate statement.
+#[moore_derive::visit]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GenerateCaseItem<'a> {
+    Default(GenerateBlock<'a>),
+    Expr(Vec<Expr<'a>>, GenerateBlock<'a>),
 }
 
 /// A body of a generate construct.
@@ -319,6 +319,10 @@ impl<'a> Lexer<'a> {
                         self.bump()?; // eat the unit
                         return Ok((Literal(Time(value, frac, unit)), sp));
                     }
+                    if let Some(exp) = self.try_exponent(&mut sp)? {
+                        let exp = name_table.intern(&exp, true);
+                        return Ok((Literal(Real(value, frac, exp)), sp));
+                    }
                     if self.peek[0].0 == CatTokenKind::Text {
                         return Err(DiagBuilder2::fatal(format!(
                             "number literal `{}` may not directly be followed by letters `{}`",
@@ -631,6 +635,45 @@ impl<'a> Lexer<'a> {
         Ok(())
     }
 
+    /// Try to parse the exponent of a real number in scientific notation.
+    ///
+    /// Returns the exponent with its optional sign, e.g. `-9` for `e-9`.
+    fn try_exponent(&mut self, span: &mut Span) -> DiagResult2<Option<String>> {
+        if self.peek[0].0 != CatTokenKind::Text {
+            return Ok(None);
+        }
+        let text = self.peek[0].1.extract();
+        if !text.starts_with('e') && !text.starts_with('E') {
+            return Ok(None);
+        }
+        let digits = &text[1..];
+
+        // Handle exponents without a sign, e.g. `e9`.
+        if !digits.is_empty() {
+            if !digits.chars().all(|c| c.is_ascii_digit()) {
+                return Ok(None);
+            }
+            span.expand(self.peek[0].1);
+            self.bump()?; // eat the exponent
+            return Ok(Some(digits.to_string()));
+        }
+
+        // Handle exponents with a sign, e.g. `e-9`.
+        let sign = match self.peek[1].0 {
+            CatTokenKind::Symbol(c @ '+') | CatTokenKind::Symbol(c @ '-') => c,
+            _ => return Ok(None),
+        };
+        if self.peek[2].0 != CatTokenKind::Digits {
+            return Ok(None);
+        }
+        let mut exp = String::new();
+        exp.push(sign);
+        self.bump()?; // eat the `e`
+        self.bump()?; // eat the sign
+        self.eat_number_body_into(&mut exp, span, false)?;
+        Ok(Some(exp))
+    }
+
     /// Try to parse the next text token as a time unit.
     fn try_time_unit(&mut self) -> Option<TimeUnit> {
         if self.peek[0].0 == CatTokenKind::Text {
@@ -856,4 +899,16 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn real_literal() {
+        check(
+            "1e9 1.0e-9 2.5E+3",
+            &[
+                Literal(Real(name("1"), None, name("9"))),
+                Literal(Real(name("1"), Some(name("0")), name("-9"))),
+                Literal(Real(name("2"), Some(name("5")), name("+3"))),
+            ],
+        );
+    }
 }
@@ -1138,10 +1138,31 @@ fn parse_item_data<'n>(p: &mut dyn AbstractParser<'n>) -> ReportedResult<ItemDat
 
         // Unsupported constructs as of now.
         SysIdent(..) => return parse_elab_system_task(p).map(|_| ItemData::Dummy),
+        Keyword(Kw::Bind) => return parse_bind_directive(p).map(|_| ItemData::Dummy),
 
         _ => (),
     }
 
+    // Catch procedural assignments that appear directly in a module body,
+    // which would otherwise produce a confusing error about the ambiguous
+    // cases below.
+    if p.is_ident()
+        && (as_assign_operator(p.peek(1).0).is_some() || p.peek(1).0 == Operator(Op::Leq))
+    {
+        let mut span = p.peek(0).1;
+        p.recover_balanced(&[Semicolon], true);
+        span.expand(p.last_span());
+        p.add_diag(
+            DiagBuilder2::error("procedural statement outside of a process")
+                .span(span)
+                .add_note(
+                    "Statements must appear in an `initial`, `always`, or `final` procedure; \
+                     use `assign` for a continuous assignment",
+                ),
+        );
+        return Err(());
+    }
+
     // Handle the possibly ambiguous cases.
     let mut pp = ParallelParser::new();
     pp.add_greedy("net declaration", |p| {
@@ -1172,6 +1193,20 @@ fn parse_elab_system_task<'n>(p: &mut dyn AbstractParser<'n>) -> ReportedResult<
     Ok(())
 }
 
+/// Skip over a bind directive and report it as unsupported.
+fn parse_bind_directive<'n>(p: &mut dyn AbstractParser<'n>) -> ReportedResult<()> {
+    let mut span = p.peek(0).1;
+    p.require_reported(Keyword(Kw::Bind))?;
+    p.recover_balanced(&[Semicolon], true);
+    span.expand(p.last_span());
+    p.add_diag(
+        DiagBuilder2::error("unsupported: bind directive")
+            .span(span)
+            .add_note("Instantiate the bound module in the target module instead."),
+    );
+    Ok(())
+}
+
 fn parse_localparam_decl<'n>(p: &mut dyn AbstractParser<'n>) -> ReportedResult<()> {
     p.require_reported(Keyword(Kw::Localparam))?;
     // TODO: Parse data type or implicit type.
@@ -2488,16 +2523,11 @@ fn parse_pattern_field<'n>(p: &mut dyn AbstractParser<'n>) -> ReportedResult<Pat
     Ok(PatternField::new(span, data))
 }
 
-pub enum StreamDir {
-    In,
-    Out,
-}
-
 fn parse_concat_expr<'n>(p: &mut dyn AbstractParser<'n>) -> ReportedResult<ExprData<'n>> {
     // Streaming concatenations have a "<<" or ">>" following the opening "{".
     let stream = match p.peek(0).0 {
-        Operator(Op::LogicShL) => Some(StreamDir::Out),
-        Operator(Op::LogicShR) => Some(StreamDir::In),
+        Operator(Op::LogicShL) => Some(StreamDir::Left),
+        Operator(Op::LogicShR) => Some(StreamDir::Right),
         _ => None,
     };
 
@@ -2545,6 +2575,7 @@ fn parse_concat_expr<'n>(p: &mut dyn AbstractParser<'n>) -> ReportedResult<ExprD
         })?;
 
         return Ok(StreamConcatExpr {
+            dir,
             slice: slice_size,
             exprs: exprs,
         });
@@ -3657,12 +3688,40 @@ fn parse_stmt_kind<'n>(
                 p.require_reported(Semicolon)?;
                 DisableForkStmt
             } else {
-                let (name, _) = p.eat_ident("task or block name")?;
+                // The target may be a hierarchical reference into a generate
+                // block, e.g. `disable g[2].blk;`.
+                let target = parse_expr_prec(p, Precedence::Postfix)?;
                 p.require_reported(Semicolon)?;
-                DisableStmt(name)
+                DisableStmt(target)
             }
         }
 
+        // Procedural continuous assignments (IEEE 1800-2009 section 10.6)
+        Keyword(Kw::Assign) => {
+            p.bump();
+            let (lhs, rhs) = parse_assignment(p)?;
+            p.require_reported(Semicolon)?;
+            ProcAssignStmt { lhs, rhs }
+        }
+        Keyword(Kw::Deassign) => {
+            p.bump();
+            let lhs = parse_expr_prec(p, Precedence::Postfix)?;
+            p.require_reported(Semicolon)?;
+            ProcDeassignStmt(lhs)
+        }
+        Keyword(Kw::Force) => {
+            p.bump();
+            let (lhs, rhs) = parse_assignment(p)?;
+            p.require_reported(Semicolon)?;
+            ForceStmt { lhs, rhs }
+        }
+        Keyword(Kw::Release) => {
+            p.bump();
+            let lhs = parse_expr_prec(p, Precedence::Postfix)?;
+            p.require_reported(Semicolon)?;
+            ReleaseStmt(lhs)
+        }
+
         // Everything else needs special treatment as things such as variable
         // declarations look very similar to other expressions.
         _ => {
@@ -4069,14 +4128,28 @@ fn try_event_control<'n>(
     }))
 }
 
-fn try_cycle_delay<'n>(p: &mut dyn AbstractParser<'n>) -> ReportedResult<Option<CycleDelay>> {
+/// Try to parse a cycle delay as described in IEEE 1800-2009 section 14.11.
+fn try_cycle_delay<'n>(p: &mut dyn AbstractParser<'n>) -> ReportedResult<Option<CycleDelay<'n>>> {
+    // Try to consume the double hashtag which introduces the cycle delay.
     if !p.try_eat(DoubleHashtag) {
         return Ok(None);
     }
+    let mut span = p.last_span();
 
-    let q = p.last_span();
-    p.add_diag(DiagBuilder2::error("Don't know how to parse cycle delay").span(q));
-    Err(())
+    // Parse the number of cycles. This may either be an integral number, an
+    // identifier, or an expression in parenthesis.
+    let (tkn, sp) = p.peek(0);
+    let expr = match tkn {
+        OpenDelim(Paren) => flanked(p, Paren, parse_expr)?,
+        Literal(Number(..)) | Ident(..) => parse_expr_first(p, Precedence::Max)?,
+        _ => {
+            p.add_diag(DiagBuilder2::error("expected cycle count or expression after ##").span(sp));
+            return Err(());
+        }
+    };
+    span.expand(p.last_span());
+
+    Ok(Some(CycleDelay { span, expr }))
 }
 
 fn parse_assignment<'n>(p: &mut dyn AbstractParser<'n>) -> ReportedResult<(Expr<'n>, Expr<'n>)> {
@@ -4333,6 +4406,7 @@ fn parse_generate_item<'n>(p: &mut dyn AbstractParser<'n>) -> ReportedResult<Ite
         Keyword(Kw::For) => ItemData::GenerateFor(parse_generate_for(p)?),
         Keyword(Kw::If) => ItemData::GenerateIf(parse_generate_if(p)?),
         Keyword(Kw::Case) => ItemData::GenerateCase(parse_generate_case(p)?),
+        OpenDelim(Bgend) => ItemData::GenerateBlock(parse_generate_block(p)?),
         _ => return parse_item(p),
     };
     span.expand(p.last_span());
@@ -4387,16 +4461,35 @@ fn parse_generate_if<'n>(p: &mut dyn AbstractParser<'n>) -> ReportedResult<Gener
     ))
 }
 
+/// Parse a generate-case construct.
+/// ```text
+/// "case" "(" expr ")" {generate_case_item} "endcase"
+/// generate_case_item: "default" [":"] generate_block
+/// generate_case_item: expr {"," expr} ":" generate_block
+/// ```
 fn parse_generate_case<'n>(p: &mut dyn AbstractParser<'n>) -> ReportedResult<GenerateCase<'n>> {
     let mut span = p.peek(0).1;
     p.require_reported(Keyword(Kw::Case))?;
-    p.recover_balanced(&[Keyword(Kw::Endcase)], true);
-    if p.try_eat(Colon) {
-        parse_identifier_name(p, "generate block label")?;
+    let expr = flanked(p, Paren, parse_expr)?;
+
+    // Parse the case items.
+    let mut items = Vec::new();
+    while p.peek(0).0 != Keyword(Kw::Endcase) && p.peek(0).0 != Eof {
+        if p.try_eat(Keyword(Kw::Default)) {
+            p.try_eat(Colon);
+            items.push(GenerateCaseItem::Default(parse_generate_block(p)?));
+        } else {
+            let mut exprs = vec![parse_expr(p)?];
+            while p.try_eat(Comma) {
+                exprs.push(parse_expr(p)?);
+            }
+            p.require_reported(Colon)?;
+            items.push(GenerateCaseItem::Expr(exprs, parse_generate_block(p)?));
+        }
     }
+    p.require_reported(Keyword(Kw::Endcase))?;
     span.expand(p.last_span());
-    p.add_diag(DiagBuilder2::error("case-generate statements not supported").span(span));
-    Ok(GenerateCase::new(span, GenerateCaseData {}))
+    Ok(GenerateCase::new(span, GenerateCaseData { expr, items }))
 }
 
 fn parse_generate_block<'n>(p: &mut dyn AbstractParser<'n>) -> ReportedResult<GenerateBlock<'n>> {
@@ -125,6 +125,9 @@ pub enum Lit {
     UnbasedUnsized(char),
     /// A number given as integer and optional fractional part.
     Number(Name, Option<Name>),
+    /// A real number in scientific notation, given as integer part, optional
+    /// fractional part, and exponent.
+    Real(Name, Option<Name>, Name),
     /// A time literal given as integer part, fractional part, and unit.
     Time(Name, Option<Name>, TimeUnit),
 }
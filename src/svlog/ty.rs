@@ -1277,6 +1277,15 @@ impl<'a> UnpackedType<'a> {
         unsafe { std::mem::transmute(ty) }
     }
 
+    /// Create a `real` type.
+    pub fn make_real() -> &'a Self {
+        static TYPE: Lazy<UnpackedType> = Lazy::new(|| UnpackedType::new(RealType::Real));
+        let ty: &UnpackedType = &TYPE;
+        // SAFETY: This is safe since the cell which causes 'a to need to
+        // outlive 'static is actually never mutated after AST construction.
+        unsafe { std::mem::transmute(ty) }
+    }
+
     /// Internalize this type in a context and resolve it.
     pub fn intern(mut self, cx: &impl TypeContext<'a>) -> &'a Self {
         let inner = match self.core {
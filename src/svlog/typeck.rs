@@ -75,7 +75,7 @@ pub(crate) fn type_of<'a>(
         HirNode::IntPort(p) => Ok(cx.type_of_int_port(Ref(p), env)),
         HirNode::ExtPort(p) => Ok(cx.type_of_ext_port(Ref(p), env)),
         HirNode::Expr(_) => Ok(cx.cast_type(node_id, env).unwrap().ty),
-        HirNode::GenvarDecl(_) => {
+        HirNode::GenvarDecl(_) | HirNode::ForeachIndex(_) => {
             Ok(SbvType::nice(ty::Domain::TwoValued, ty::Sign::Signed, 32).to_unpacked(cx))
         }
         HirNode::EnumVariant(v) => {
@@ -127,6 +127,9 @@ pub(crate) fn type_of_int_port<'a>(
         Some(ref data) => {
             // Determine the type of the port.
             let ty = cx.unpacked_type_from_ast(Ref(data.ty), Ref(data.unpacked_dims), env, None);
+            if !ty.is_error() {
+                check_port_dims(cx, port, data, env);
+            }
 
             // Check if this is an interface that may be implicitly
             // parametrized.
@@ -170,6 +173,90 @@ pub(crate) fn type_of_int_port<'a>(
     }
 }
 
+/// Check that the dimensions of a non-ANSI port agree with the dimensions of
+/// its net or variable declaration.
+///
+/// The bounds are compared after evaluation, such that `[7:0]` and `[8-1:0]`
+/// are considered the same.
+fn check_port_dims<'a>(
+    cx: &impl Context<'a>,
+    port: &'a port_list::IntPort<'a>,
+    data: &'a port_list::IntPortData<'a>,
+    env: ParamEnv,
+) {
+    let (decl_span, decl_packed, decl_unpacked) = match data.matching_dims {
+        Some(x) => x,
+        None => return,
+    };
+    let dims: [(&str, &'a [ast::TypeDim<'a>], &'a [ast::TypeDim<'a>]); 2] = [
+        ("packed", &data.ty.dims, decl_packed),
+        ("unpacked", data.unpacked_dims, decl_unpacked),
+    ];
+    for &(kind, port_dims, decl_dims) in &dims {
+        if decl_dims.is_empty() {
+            continue;
+        }
+        let packed = kind == "packed";
+        let port_ranges = ranges_from_dims(cx, port_dims, packed, env, port.span);
+        let decl_ranges = ranges_from_dims(cx, decl_dims, packed, env, decl_span);
+        match (port_ranges, decl_ranges) {
+            (Ok(a), Ok(b)) if a != b => {
+                let desc = |dims: &[ast::TypeDim]| -> String {
+                    dims.iter()
+                        .map(|d| d.desc_full())
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                };
+                cx.emit(
+                    DiagBuilder2::error(format!(
+                        "port `{}` has contradicting {} dimensions",
+                        port.name, kind
+                    ))
+                    .span(port.span)
+                    .span(decl_span)
+                    .add_note(format!(
+                        "Port declared with {}, but net/variable declared with {}",
+                        desc(port_dims),
+                        desc(decl_dims)
+                    )),
+                );
+            }
+            _ => (),
+        }
+    }
+}
+
+/// Evaluate the bounds of a list of dimensions.
+///
+/// A dimension given as a size `[N]` maps to `[N-1:0]` if it is packed, and
+/// to `[0:N-1]` if it is unpacked. Dimensions without bounds, such as queues
+/// and associative arrays, map to `None`.
+fn ranges_from_dims<'a>(
+    cx: &impl Context<'a>,
+    dims: &'a [ast::TypeDim<'a>],
+    packed: bool,
+    env: ParamEnv,
+    span: Span,
+) -> Result<Vec<Option<ty::Range>>> {
+    dims.iter()
+        .map(|dim| match dim {
+            ast::TypeDim::Expr(size) => Ok(Some(ty::Range {
+                size: size_from_bounds_expr(cx, size.id(), env, span)?,
+                dir: if packed {
+                    ty::RangeDir::Down
+                } else {
+                    ty::RangeDir::Up
+                },
+                offset: 0,
+            })),
+            ast::TypeDim::Range(lhs, rhs) => {
+                range_from_bounds_exprs(cx, lhs.id(), rhs.id(), env, span).map(Some)
+            }
+            _ => Ok(None),
+        })
+        .collect()
+}
+
 /// Determine the type of an external port.
 #[moore_derive::query]
 pub(crate) fn type_of_ext_port<'a>(
@@ -827,10 +914,41 @@ pub(crate) fn packed_type_from_ast<'a>(
                     ..*intf
                 };
                 Unpacked(UnpackedCore::Interface(new_intf))
+            } else if let Some(strukt) = inner_ty.get_struct() {
+                // Select the type of the struct member.
+                let member = match strukt.members.iter().find(|m| m.name.value == name.value) {
+                    Some(x) => x,
+                    None => {
+                        cx.emit(
+                            DiagBuilder2::error(format!(
+                                "type `{}` has no member `{}`",
+                                inner_ty, name
+                            ))
+                            .span(name.span)
+                            .add_note(format!("`{}` was defined here:", inner_ty))
+                            .span(strukt.ast.span()),
+                        );
+                        return UnpackedType::make_error();
+                    }
+                };
+                if let Some(ty) = member.ty.get_packed() {
+                    Packed(PackedCore::Ref {
+                        span: ast.kind.span(),
+                        ty,
+                    })
+                } else {
+                    Unpacked(UnpackedCore::Ref {
+                        span: ast.kind.span(),
+                        ty: member.ty,
+                    })
+                }
             } else {
                 cx.emit(
-                    DiagBuilder2::error(format!("`{}` is not an interface", ty.span().extract()))
-                        .span(ty.span()),
+                    DiagBuilder2::error(format!(
+                        "`{}` is neither an interface nor a struct",
+                        ty.span().extract()
+                    ))
+                    .span(ty.span()),
                 );
                 error!("Offending AST: {:#2?}", ast);
                 return UnpackedType::make_error();
@@ -882,6 +1000,19 @@ pub(crate) fn packed_type_from_ast<'a>(
             for dim in &ast.dims {
                 match dim {
                     ast::TypeDim::Unsized => dims.push(ty::PackedDim::Unsized),
+                    ast::TypeDim::Expr(size) => {
+                        match size_from_bounds_expr(cx, size.id(), env, ast.span()) {
+                            Ok(size) => dims.push(ty::PackedDim::Range(ty::Range {
+                                size,
+                                dir: ty::RangeDir::Down,
+                                offset: 0,
+                            })),
+                            Err(()) => {
+                                failed = true;
+                                continue;
+                            }
+                        }
+                    }
                     ast::TypeDim::Range(lhs, rhs) => {
                         match range_from_bounds_exprs(cx, lhs.id(), rhs.id(), env, ast.span()) {
                             Ok(r) => dims.push(ty::PackedDim::Range(r)),
@@ -1075,10 +1206,12 @@ pub(crate) fn type_of_expr<'a>(
         // These expressions have a fully self-determined type.
         hir::ExprKind::IntConst { .. }
         | hir::ExprKind::TimeConst(..)
+        | hir::ExprKind::RealConst(..)
         | hir::ExprKind::StringConst(..)
         | hir::ExprKind::Ident(..)
         | hir::ExprKind::Scope(..)
         | hir::ExprKind::Concat(..)
+        | hir::ExprKind::StreamConcat { .. }
         | hir::ExprKind::Cast(..)
         | hir::ExprKind::CastSign(..)
         | hir::ExprKind::CastSize(..)
@@ -1091,6 +1224,8 @@ pub(crate) fn type_of_expr<'a>(
         | hir::ExprKind::Builtin(hir::BuiltinCall::OneHot0(_))
         | hir::ExprKind::Builtin(hir::BuiltinCall::IsUnknown(_))
         | hir::ExprKind::Builtin(hir::BuiltinCall::ArrayDim(..))
+        | hir::ExprKind::Builtin(hir::BuiltinCall::Sscanf(..))
+        | hir::ExprKind::Builtin(hir::BuiltinCall::Fscanf(..))
         | hir::ExprKind::Field(..)
         | hir::ExprKind::Index(..)
         | hir::ExprKind::Assign { .. } => cx.need_self_determined_type(expr.id, env),
@@ -1167,12 +1302,24 @@ pub(crate) fn type_of_expr<'a>(
         // Other things simply evaluate to their self-determined type.
         hir::ExprKind::Builtin(hir::BuiltinCall::Signed(_))
         | hir::ExprKind::Builtin(hir::BuiltinCall::Unsigned(_))
-        | hir::ExprKind::FunctionCall(..) => cx.need_self_determined_type(expr.id, env),
+        | hir::ExprKind::FunctionCall(..)
+        | hir::ExprKind::MethodCall { .. } => cx.need_self_determined_type(expr.id, env),
 
-        // Pattern expressions require a type context.
+        // Pattern expressions, empty queues, and `null` require a type
+        // context.
         hir::ExprKind::PositionalPattern(..)
         | hir::ExprKind::NamedPattern(..)
-        | hir::ExprKind::RepeatPattern(..) => cx.need_type_context(Ref(expr), env).ty(),
+        | hir::ExprKind::RepeatPattern(..)
+        | hir::ExprKind::EmptyQueue
+        | hir::ExprKind::NullLiteral => cx.need_type_context(Ref(expr), env).ty(),
+
+        // The unbounded `$` assumes the type of its context if there is one,
+        // e.g. in an `inside` range, and otherwise acts as an integer index as
+        // in `q[$]`.
+        hir::ExprKind::Unbounded => match cx.type_context(Ref(expr), env) {
+            Some(ty) => ty.ty(),
+            None => PackedType::make(cx, ty::IntAtomType::Int).to_unpacked(cx),
+        },
 
         hir::ExprKind::Ast(expr) => bug_span!(expr.span(), cx, "unsupported raw AST expr in HIR"),
     }
@@ -1597,6 +1744,7 @@ fn self_determined_expr_type<'gcx>(
 
         // Time constants are of time type.
         hir::ExprKind::TimeConst(_) => Some(UnpackedType::make_time()),
+        hir::ExprKind::RealConst(_) => Some(UnpackedType::make_real()),
 
         // String literals behave like a packed array containing the characters.
         hir::ExprKind::StringConst(string) => Some(
@@ -1616,15 +1764,55 @@ fn self_determined_expr_type<'gcx>(
         // node.
         hir::ExprKind::Ident(_) | hir::ExprKind::Scope(..) => Some(
             cx.resolve_node(expr.id, env)
-                .and_then(|x| cx.type_of(x, env))
+                .and_then(|x| match cx.ast_for_id(x).as_all() {
+                    // Generate blocks can only be referenced through a field
+                    // access into a named block or one iteration of a generate
+                    // loop, which is handled separately.
+                    ast::AllNode::GenerateBlock(..)
+                    | ast::AllNode::GenerateFor(..)
+                    | ast::AllNode::GenerateIf(..)
+                    | ast::AllNode::GenerateCase(..) => {
+                        cx.emit(
+                            DiagBuilder2::error(format!(
+                                "unsupported: hierarchical reference into generate block `{}`",
+                                expr.span.extract()
+                            ))
+                            .span(expr.span)
+                            .add_note(
+                                "Only declarations in a named generate block or an iteration \
+                                 of a generate loop can be accessed, for example `blk.x` or \
+                                 `g[0].x`.",
+                            ),
+                        );
+                        Err(())
+                    }
+                    // Functions cannot be used as values. Their return value
+                    // may only be provided through a `return` statement.
+                    ast::AllNode::SubroutineDecl(decl) => {
+                        cx.emit(
+                            DiagBuilder2::error(format!(
+                                "unsupported: function `{}` used as a value",
+                                decl.prototype.name
+                            ))
+                            .span(expr.span)
+                            .add_note(
+                                "Assigning the return value to the function name is not \
+                                 supported; use a `return` statement instead.",
+                            ),
+                        );
+                        Err(())
+                    }
+                    _ => cx.type_of(x, env),
+                })
                 .unwrap_or(UnpackedType::make_error()),
         ),
 
         // Concatenation yields an unsigned logic vector whose bit width is the
         // sum of the simple bit vector types of each argument.
         //
-        // See §11.8.1 "Rules for expression types".
-        hir::ExprKind::Concat(repeat, ref exprs) => {
+        // See §11.8.1 "Rules for expression types". Streaming concatenations
+        // only reorder the bits and yield the same type.
+        hir::ExprKind::Concat(_, ref exprs) | hir::ExprKind::StreamConcat { ref exprs, .. } => {
             let mut failed = false;
 
             // Determine the cumulative width of all fields.
@@ -1660,15 +1848,17 @@ fn self_determined_expr_type<'gcx>(
             }
 
             // Determine the repetition factor.
-            let repeat = match repeat {
-                Some(repeat) => match cx.constant_int_value_of(repeat, env) {
-                    Ok(r) => r.to_usize().unwrap(),
-                    Err(()) => {
-                        failed = true;
-                        0
+            let repeat = match expr.kind {
+                hir::ExprKind::Concat(Some(repeat), _) => {
+                    match cx.constant_int_value_of(repeat, env) {
+                        Ok(r) => r.to_usize().unwrap(),
+                        Err(()) => {
+                            failed = true;
+                            0
+                        }
                     }
-                },
-                None => 1,
+                }
+                _ => 1,
             };
 
             // Package up the result.
@@ -1742,7 +1932,9 @@ fn self_determined_expr_type<'gcx>(
         | hir::ExprKind::Builtin(hir::BuiltinCall::Clog2(_))
         | hir::ExprKind::Builtin(hir::BuiltinCall::Bits(_))
         | hir::ExprKind::Builtin(hir::BuiltinCall::CountOnes(_))
-        | hir::ExprKind::Builtin(hir::BuiltinCall::ArrayDim(..)) => {
+        | hir::ExprKind::Builtin(hir::BuiltinCall::ArrayDim(..))
+        | hir::ExprKind::Builtin(hir::BuiltinCall::Sscanf(..))
+        | hir::ExprKind::Builtin(hir::BuiltinCall::Fscanf(..)) => {
             Some(PackedType::make(cx, ty::IntAtomType::Int).to_unpacked(cx))
         }
 
@@ -1755,9 +1947,32 @@ fn self_determined_expr_type<'gcx>(
 
         // Member field accesses resolve to the type of the member.
         hir::ExprKind::Field(target, name) => {
+            // References into an iteration of a generate loop resolve to the
+            // type of the declaration in that iteration.
+            match cx.resolve_genblk_member(expr.id, env) {
+                Ok(Some(member)) => {
+                    return Some(
+                        cx.type_of(member.decl, member.env)
+                            .unwrap_or(UnpackedType::make_error()),
+                    )
+                }
+                Ok(None) => (),
+                Err(()) => return Some(UnpackedType::make_error()),
+            }
+            // References into a named generate block resolve to the type of
+            // the declaration in that block.
+            match cx.resolve_block_member(expr.id, env) {
+                Ok(Some(decl)) => {
+                    return Some(cx.type_of(decl, env).unwrap_or(UnpackedType::make_error()))
+                }
+                Ok(None) => (),
+                Err(()) => return Some(UnpackedType::make_error()),
+            }
             let target_ty = cx.self_determined_type(target, env)?;
             if let Some(intf) = target_ty.get_interface() {
-                let def = cx.resolve_hierarchical_or_error(name, intf.ast).ok()?;
+                let def = cx
+                    .resolve_intf_member_or_error(name, intf.ast, intf.modport)
+                    .ok()?;
                 // If we are selecting a modport, just modify the type of the
                 // expression and let the implicit casting logic take care of
                 // the rest.
@@ -1831,6 +2046,82 @@ fn self_determined_expr_type<'gcx>(
                 return Some(target_ty);
             }
 
+            // Make sure absolute part-selects follow the direction of the
+            // vector they select from, e.g. `x[3:5]` into `logic [7:0] x` is
+            // illegal.
+            if let hir::IndexMode::Many(ast::RangeMode::Absolute, lhs, rhs) = mode {
+                let dir = match target_ty.outermost_dim() {
+                    Some(dim) => dim.get_range().map(|r| r.dir),
+                    None => Some(ty::RangeDir::Down),
+                };
+                let lhs_int = cx.constant_int_value_of(lhs, env);
+                let rhs_int = cx.constant_int_value_of(rhs, env);
+                if let (Some(dir), Ok(lhs_int), Ok(rhs_int)) = (dir, lhs_int, rhs_int) {
+                    let reversed = match dir {
+                        ty::RangeDir::Down => lhs_int < rhs_int,
+                        ty::RangeDir::Up => lhs_int > rhs_int,
+                    };
+                    if reversed {
+                        cx.emit(
+                            DiagBuilder2::error(format!(
+                                "part-select `{}` is reversed",
+                                expr.span.extract()
+                            ))
+                            .span(expr.span)
+                            .add_note(format!(
+                                "Selected value is of type `{}`, whose range is {}",
+                                target_ty,
+                                match dir {
+                                    ty::RangeDir::Down => "descending",
+                                    ty::RangeDir::Up => "ascending",
+                                }
+                            )),
+                        );
+                        return Some(UnpackedType::make_error());
+                    }
+                }
+            }
+
+            // Associative arrays are accessed by a single key. Keys into arrays
+            // indexed by `string` must be strings themselves.
+            if let Some(ty::Dim::Unpacked(ty::UnpackedDim::Assoc(key_ty))) =
+                target_ty.outermost_dim()
+            {
+                let index = match mode {
+                    hir::IndexMode::One(index) => index,
+                    hir::IndexMode::Many(..) => {
+                        cx.emit(
+                            DiagBuilder2::error(format!(
+                                "cannot part-select associative array of type `{}`",
+                                target_ty
+                            ))
+                            .span(expr.span),
+                        );
+                        return Some(UnpackedType::make_error());
+                    }
+                };
+                if key_ty.map(|ty| ty.is_string()).unwrap_or(false) {
+                    let is_string_literal = match cx.hir_of(index) {
+                        Ok(HirNode::Expr(e)) => matches!(e.kind, hir::ExprKind::StringConst(..)),
+                        _ => false,
+                    };
+                    let index_ty = cx.need_self_determined_type(index, env);
+                    if !is_string_literal && !index_ty.is_string() && !index_ty.is_error() {
+                        cx.emit(
+                            DiagBuilder2::error(format!(
+                                "key `{}` of type `{}` cannot index into `{}`",
+                                cx.span(index).extract(),
+                                index_ty,
+                                target_ty
+                            ))
+                            .span(cx.span(index))
+                            .add_note("Associative arrays indexed by `string` require string keys"),
+                        );
+                        return Some(UnpackedType::make_error());
+                    }
+                }
+            }
+
             // If we are selecting a slice (width not None), the result type is
             // the array, but with the outermost array dimension changed. If we
             // are selecting a bit, the result is the type with the selected
@@ -1956,6 +2247,23 @@ fn self_determined_expr_type<'gcx>(
             Some(return_type_of_function(cx, &target.prototype, env))
         }
 
+        // Method calls either query the receiver, return one of its elements,
+        // or return nothing at all.
+        hir::ExprKind::MethodCall {
+            receiver, method, ..
+        } => match method.value {
+            hir::BuiltinMethod::Size | hir::BuiltinMethod::Num | hir::BuiltinMethod::Exists => {
+                Some(PackedType::make(cx, ty::IntAtomType::Int).to_unpacked(cx))
+            }
+            hir::BuiltinMethod::PopFront | hir::BuiltinMethod::PopBack => {
+                element_type_of_receiver(cx, receiver, method, env)
+            }
+            hir::BuiltinMethod::Delete
+            | hir::BuiltinMethod::Insert
+            | hir::BuiltinMethod::PushFront
+            | hir::BuiltinMethod::PushBack => Some(UnpackedType::make_void()),
+        },
+
         // Assignment expressions produce the value of the assigned variable as
         // their own value, which is basically the self-determined type of the
         // lhs, if available, and otherwise the rhs.
@@ -1967,6 +2275,34 @@ fn self_determined_expr_type<'gcx>(
     }
 }
 
+/// Determine the element type of the receiver of an array or queue method.
+fn element_type_of_receiver<'gcx>(
+    cx: &impl Context<'gcx>,
+    receiver: NodeId,
+    method: Spanned<hir::BuiltinMethod>,
+    env: ParamEnv,
+) -> Option<&'gcx UnpackedType<'gcx>> {
+    let ty = cx.need_self_determined_type(receiver, env);
+    if ty.is_error() {
+        return Some(ty);
+    }
+    match ty.outermost_dim() {
+        Some(ty::Dim::Unpacked(..)) => ty.pop_dim(cx),
+        _ => {
+            cx.emit(
+                DiagBuilder2::error(format!(
+                    "method `{}` requires an unpacked array or queue, but `{}` is of type `{}`",
+                    method.value,
+                    cx.span(receiver).extract(),
+                    ty
+                ))
+                .span(method.span),
+            );
+            Some(UnpackedType::make_error())
+        }
+    }
+}
+
 fn self_determined_sign_cast_type<'gcx>(
     cx: &impl Context<'gcx>,
     sign: Sign,
@@ -2320,6 +2656,11 @@ pub(crate) fn type_context<'a>(
                     inst
                 ),
             };
+            // Connections to instance arrays are self-determined, since they
+            // may carry the concatenation of all elements' ports.
+            if !inst.ast.dims.is_empty() {
+                return None;
+            }
             let details = cx.inst_details(Ref(inst), env).ok()?;
             let port = details.ports.reverse_find(onto.id())?;
             return Some(cx.type_of_ext_port(Ref(port), details.inner_env).into());
@@ -2346,6 +2687,9 @@ pub(crate) fn type_context<'a>(
                 cx.self_determined_type(a.rhs, env).map(Into::into)
             } else if a.rhs == onto {
                 cx.self_determined_type(a.lhs, env).map(Into::into)
+            } else if a.delay == Some(onto) {
+                // Assignment delays require time arguments.
+                Some(UnpackedType::make_time().into())
             } else {
                 None
             }
@@ -2358,7 +2702,7 @@ pub(crate) fn type_context<'a>(
         },
         HirNode::VarDecl(v) if v.init == Some(onto) => {
             let ty = cx.ast_for_id(v.ty).as_all().get_type().unwrap();
-            if !ty.is_implicit() || v.kind != ast::VarKind::Var {
+            if !ty.is_implicit() {
                 Some(
                     cx.type_of(parent.id(), env)
                         .unwrap_or(UnpackedType::make_error())
@@ -2368,6 +2712,11 @@ pub(crate) fn type_context<'a>(
                 None
             }
         }
+        HirNode::NetDecl(v) if v.init == Some(onto) => Some(
+            cx.type_of(parent.id(), env)
+                .unwrap_or(UnpackedType::make_error())
+                .into(),
+        ),
         HirNode::ValueParam(v) if v.default == Some(onto) => {
             let ty = cx.ast_for_id(v.ty).as_all().get_type().unwrap();
             if !ty.is_implicit() {
@@ -2559,9 +2908,9 @@ fn type_context_imposed_by_expr<'gcx>(
             Some(cx.need_self_determined_type(expr.id, env).into())
         }
 
-        // Concatenations require their arguments (including repetition counts)
-        // to map to a corresponding SBVT.
-        hir::ExprKind::Concat(..) => {
+        // Concatenations require their arguments (including repetition counts
+        // and slice sizes) to map to a corresponding SBVT.
+        hir::ExprKind::Concat(..) | hir::ExprKind::StreamConcat { .. } => {
             let ty = cx.need_self_determined_type(onto, env);
             if ty.is_error() {
                 return Some(ty.into());
@@ -2619,9 +2968,45 @@ fn type_context_imposed_by_expr<'gcx>(
             Some(opty.into())
         }
 
+        // Indices into generate loops are self-determined.
+        hir::ExprKind::Index(target, _) if resolver::is_genblk_ref(cx, target, env) => None,
+
+        // Indices into associative arrays are cast to the array's key type.
+        hir::ExprKind::Index(target, hir::IndexMode::One(index)) if onto == index => {
+            match cx.need_operation_type(expr.id, env).outermost_dim() {
+                Some(ty::Dim::Unpacked(ty::UnpackedDim::Assoc(Some(key_ty)))) => {
+                    Some(key_ty.into())
+                }
+                _ => None,
+            }
+        }
+
         // Assignments impose their operation type as context.
         hir::ExprKind::Assign { .. } => Some(cx.need_operation_type(expr.id, env).into()),
 
+        // Items inserted into a queue are cast to its element type, and
+        // indices to an integer.
+        hir::ExprKind::MethodCall {
+            receiver,
+            method,
+            ref args,
+        } if receiver != onto => {
+            let is_item = match method.value {
+                hir::BuiltinMethod::PushFront | hir::BuiltinMethod::PushBack => true,
+                hir::BuiltinMethod::Insert => args.get(1) == Some(&onto),
+                _ => false,
+            };
+            if is_item {
+                element_type_of_receiver(cx, receiver, method, env).map(Into::into)
+            } else {
+                Some(
+                    PackedType::make(cx, ty::IntAtomType::Int)
+                        .to_unpacked(cx)
+                        .into(),
+                )
+            }
+        }
+
         _ => None,
     }
 }
@@ -2679,7 +3064,9 @@ fn type_context_imposed_by_stmt<'gcx>(
     match stmt.kind {
         // Assignments impose the self-determined type of the other operand on
         // an operand, if available.
-        hir::StmtKind::Assign { lhs, rhs, .. } => {
+        hir::StmtKind::Assign { lhs, rhs, .. }
+        | hir::StmtKind::ProcAssign { lhs, rhs }
+        | hir::StmtKind::Force { lhs, rhs } => {
             if lhs == onto {
                 cx.self_determined_type(rhs, env).map(Into::into)
             } else if rhs == onto {
@@ -2692,9 +3079,15 @@ fn type_context_imposed_by_stmt<'gcx>(
         // If statements and do/while loops require a boolean condition.
         hir::StmtKind::If { cond, .. } if onto == cond => Some(TypeContext::Bool),
 
+        // Assertions require a boolean condition.
+        hir::StmtKind::Assert { cond, .. } if onto == cond => Some(TypeContext::Bool),
+
+        // Wait statements require a boolean condition.
+        hir::StmtKind::Wait { cond, .. } if onto == cond => Some(TypeContext::Bool),
+
         // Do/while loops require a boolean condition.
-        hir::StmtKind::Loop { kind, .. } => {
-            match kind {
+        hir::StmtKind::Loop { ref kind, .. } => {
+            match *kind {
                 hir::LoopKind::Repeat(count) if onto == count => {
                     // TODO: Actually this should require a simple 2-value bit vector type
                     None
@@ -2710,13 +3103,31 @@ fn type_context_imposed_by_stmt<'gcx>(
             }
         }
 
-        // Case statements impose the switch expression's self-determined type
-        // on  the case arms.
+        // Case statements compare the switch expression and the case arms at
+        // the width of the widest of them (IEEE 1800-2017 section 12.5). If
+        // the switch expression already has that common type, its
+        // self-determined type is imposed on the case arms.
         hir::StmtKind::Case { expr, ref ways, .. } => {
-            if ways.iter().flat_map(|(x, _)| x.iter()).any(|&x| x == onto) {
-                cx.self_determined_type(expr, env).map(Into::into)
-            } else {
-                None
+            let is_label = ways.iter().flat_map(|(x, _)| x.iter()).any(|&x| x == onto);
+            if !is_label && expr != onto {
+                return None;
+            }
+            let expr_ty = cx.self_determined_type(expr, env)?;
+            let common_ty = unify_operator_types(
+                cx,
+                env,
+                std::iter::once(expr)
+                    .chain(ways.iter().flat_map(|(x, _)| x.iter().cloned()))
+                    .flat_map(|id| cx.self_determined_type(id, env)),
+            );
+            let extended = match (expr_ty.get_simple_bit_vector(), common_ty) {
+                (Some(sbv), Some(ty)) => !ty.get_simple_bit_vector()?.is_identical(&sbv),
+                _ => false,
+            };
+            match (extended, is_label) {
+                (true, _) => common_ty.map(Into::into),
+                (false, true) => Some(expr_ty.into()),
+                (false, false) => None,
             }
         }
 
@@ -2815,7 +3226,11 @@ pub(crate) fn expr_is_lvalue<'gcx>(cx: &impl Context<'gcx>, onto: NodeId, _env:
             _ => false,
         },
         HirNode::Stmt(s) => match s.kind {
-            hir::StmtKind::Assign { lhs, .. } => lhs == onto,
+            hir::StmtKind::Assign { lhs, .. }
+            | hir::StmtKind::ProcAssign { lhs, .. }
+            | hir::StmtKind::ProcDeassign { lhs }
+            | hir::StmtKind::Force { lhs, .. }
+            | hir::StmtKind::Release { lhs } => lhs == onto,
             _ => false,
         },
         HirNode::Assign(a) => a.lhs == onto,
@@ -14,12 +14,14 @@
 use crate::{
     crate_prelude::*,
     hir::HirNode,
+    mir::WalkVisitor as _,
     ty::{SbvType, UnpackedType},
     ParamEnv, ParamEnvBinding,
 };
 use bit_vec::BitVec;
 use itertools::Itertools;
 use num::{BigInt, BigRational, Integer, One, ToPrimitive, Zero};
+use std::{cell::RefCell, collections::HashMap};
 
 /// A verilog value.
 pub type Value<'t> = &'t ValueData<'t>;
@@ -287,7 +289,26 @@ fn constant_value_of_inner<'a>(cx: &impl Context<'a>, node_id: NodeId, env: Para
                     Ok(x) => x,
                     _ => return cx.intern_value(make_error(UnpackedType::make_error())),
                 };
-                cx.intern_value(make_int(ty, var.index.into()))
+                // Variants without an explicit value are one greater than the
+                // previous variant, with the first variant being zero.
+                let prev = match var.index.checked_sub(1) {
+                    Some(index) => match cx.hir_of(var.enum_id) {
+                        Ok(HirNode::Type(hir::Type {
+                            kind: hir::TypeKind::Enum(ref variants, _),
+                            ..
+                        })) => Some(variants[index].1),
+                        _ => return cx.intern_value(make_error(ty)),
+                    },
+                    None => None,
+                };
+                let value = match prev {
+                    Some(prev) => match cx.constant_value_of(prev, env).get_int() {
+                        Some(v) => v + 1,
+                        None => return cx.intern_value(make_error(ty)),
+                    },
+                    None => num::zero(),
+                };
+                cx.intern_value(make_int(ty, value))
             }
         },
         _ => {
@@ -351,7 +372,7 @@ pub(crate) fn const_mir_rvalue<'a>(
     cx: &impl Context<'a>,
     Ref(mir): Ref<'a, mir::Rvalue<'a>>,
 ) -> Value<'a> {
-    let v = const_mir_rvalue_inner(cx, mir);
+    let v = const_mir_rvalue_inner(cx, mir, None);
     if cx.sess().has_verbosity(Verbosity::CONSTS) {
         let ext = mir.span.extract();
         let line = mir.span.begin().human_line();
@@ -360,7 +381,99 @@ pub(crate) fn const_mir_rvalue<'a>(
     v
 }
 
-fn const_mir_rvalue_inner<'a>(cx: &impl Context<'a>, mir: &'a mir::Rvalue<'a>) -> Value<'a> {
+/// The maximum nesting depth of constant function calls.
+const MAX_CONST_CALL_DEPTH: usize = 256;
+
+/// The maximum number of loop iterations in a constant function.
+const MAX_CONST_LOOP_ITERATIONS: usize = 1 << 16;
+
+/// The local state of a constant function being evaluated.
+struct ConstFrame<'a> {
+    /// The current values of the function's arguments and local variables.
+    values: RefCell<HashMap<NodeId, Value<'a>>>,
+    /// The number of constant function calls this frame is nested in.
+    depth: usize,
+}
+
+/// How execution proceeds after a statement in a constant function.
+enum ConstFlow<'a> {
+    /// Continue with the next statement.
+    Next,
+    /// Leave the innermost loop.
+    Break,
+    /// Start the next iteration of the innermost loop.
+    Continue,
+    /// Return from the function with an optional value.
+    Return(Option<Value<'a>>),
+}
+
+/// Evaluate an MIR rvalue, taking local variables of a constant function into
+/// account if within one.
+fn const_eval<'a>(
+    cx: &impl Context<'a>,
+    mir: &'a mir::Rvalue<'a>,
+    frame: Option<&ConstFrame<'a>>,
+) -> Value<'a> {
+    match frame {
+        Some(_) => const_mir_rvalue_inner(cx, mir, frame),
+        None => cx.const_mir_rvalue(Ref(mir)),
+    }
+}
+
+/// Evaluate an MIR rvalue to a constant integer, taking local variables of a
+/// constant function into account if within one.
+fn const_eval_int<'a>(
+    cx: &impl Context<'a>,
+    mir: &'a mir::Rvalue<'a>,
+    frame: Option<&ConstFrame<'a>>,
+) -> Result<&'a num::BigInt> {
+    if frame.is_none() {
+        return cx.const_mir_rvalue_int(Ref(mir));
+    }
+    match const_eval(cx, mir, frame).kind {
+        ValueKind::Int(ref x, ..) => Ok(x),
+        ValueKind::Error => Err(()),
+        _ => {
+            cx.emit(
+                DiagBuilder2::error(format!(
+                    "`{}` is not a constant integer",
+                    mir.span.extract()
+                ))
+                .span(mir.span),
+            );
+            Err(())
+        }
+    }
+}
+
+/// Evaluate an MIR rvalue to a constant string, taking local variables of a
+/// constant function into account if within one.
+fn const_eval_string<'a>(
+    cx: &impl Context<'a>,
+    mir: &'a mir::Rvalue<'a>,
+    frame: Option<&ConstFrame<'a>>,
+) -> Result<&'a Vec<u8>> {
+    if frame.is_none() {
+        return cx.const_mir_rvalue_string(Ref(mir));
+    }
+    match const_eval(cx, mir, frame).kind {
+        ValueKind::String(ref x) => Ok(x),
+        ValueKind::Error => Err(()),
+        _ => {
+            cx.emit(
+                DiagBuilder2::error(format!("`{}` is not a constant string", mir.span.extract()))
+                    .span(mir.span),
+            );
+            Err(())
+        }
+    }
+}
+
+fn const_mir_rvalue_inner<'a>(
+    cx: &impl Context<'a>,
+    mir: &'a mir::Rvalue<'a>,
+    frame: Option<&ConstFrame<'a>>,
+) -> Value<'a> {
     // Propagate MIR tombstones immediately.
     if mir.is_error() {
         return cx.intern_value(make_error(mir.ty));
@@ -379,7 +492,7 @@ fn const_mir_rvalue_inner<'a>(cx: &impl Context<'a>, mir: &'a mir::Rvalue<'a>) -
                 value.ty,
                 mir.ty
             );
-            let v = cx.const_mir_rvalue(value.into());
+            let v = const_eval(cx, value, frame);
             // TODO: This is an incredibly ugly hack.
             cx.intern_value(ValueData {
                 ty: mir.ty,
@@ -388,7 +501,7 @@ fn const_mir_rvalue_inner<'a>(cx: &impl Context<'a>, mir: &'a mir::Rvalue<'a>) -
         }
 
         mir::RvalueKind::Transmute(value) => {
-            let v = cx.const_mir_rvalue(value.into());
+            let v = const_eval(cx, value, frame);
             cx.intern_value(ValueData {
                 ty: mir.ty,
                 kind: v.kind.clone(),
@@ -396,7 +509,7 @@ fn const_mir_rvalue_inner<'a>(cx: &impl Context<'a>, mir: &'a mir::Rvalue<'a>) -
         }
 
         mir::RvalueKind::CastToBool(value) => {
-            let value = cx.const_mir_rvalue(value.into());
+            let value = const_eval(cx, value, frame);
             if value.is_error() {
                 return cx.intern_value(make_error(mir.ty));
             }
@@ -404,7 +517,7 @@ fn const_mir_rvalue_inner<'a>(cx: &impl Context<'a>, mir: &'a mir::Rvalue<'a>) -
         }
 
         mir::RvalueKind::ApplyTimescale(value, ref scale) => {
-            let value = cx.const_mir_rvalue(value.into());
+            let value = const_eval(cx, value, frame);
             if value.is_error() {
                 return cx.intern_value(make_error(mir.ty));
             }
@@ -417,7 +530,7 @@ fn const_mir_rvalue_inner<'a>(cx: &impl Context<'a>, mir: &'a mir::Rvalue<'a>) -
         mir::RvalueKind::ConstructArray(ref values) => cx.intern_value(make_array(
             mir.ty,
             (0..values.len())
-                .map(|index| cx.const_mir_rvalue(values[&index].into()))
+                .map(|index| const_eval(cx, values[&index], frame))
                 .collect(),
         )),
 
@@ -425,14 +538,14 @@ fn const_mir_rvalue_inner<'a>(cx: &impl Context<'a>, mir: &'a mir::Rvalue<'a>) -
             mir.ty,
             values
                 .iter()
-                .map(|&value| cx.const_mir_rvalue(value.into()))
+                .map(|&value| const_eval(cx, value, frame))
                 .collect(),
         )),
 
         mir::RvalueKind::Const(value) => value,
 
         mir::RvalueKind::UnaryBitwise { op, arg } => {
-            let arg_val = cx.const_mir_rvalue(arg.into());
+            let arg_val = const_eval(cx, arg, frame);
             if arg_val.is_error() {
                 return cx.intern_value(make_error(mir.ty));
             }
@@ -451,8 +564,8 @@ fn const_mir_rvalue_inner<'a>(cx: &impl Context<'a>, mir: &'a mir::Rvalue<'a>) -
         }
 
         mir::RvalueKind::BinaryBitwise { op, lhs, rhs } => {
-            let lhs_val = cx.const_mir_rvalue(lhs.into());
-            let rhs_val = cx.const_mir_rvalue(rhs.into());
+            let lhs_val = const_eval(cx, lhs, frame);
+            let rhs_val = const_eval(cx, rhs, frame);
             if lhs_val.is_error() || rhs_val.is_error() {
                 return cx.intern_value(make_error(mir.ty));
             }
@@ -474,7 +587,7 @@ fn const_mir_rvalue_inner<'a>(cx: &impl Context<'a>, mir: &'a mir::Rvalue<'a>) -
         }
 
         mir::RvalueKind::IntUnaryArith { op, arg, .. } => {
-            let arg_val = cx.const_mir_rvalue(arg.into());
+            let arg_val = const_eval(cx, arg, frame);
             if arg_val.is_error() {
                 return cx.intern_value(make_error(mir.ty));
             }
@@ -488,8 +601,8 @@ fn const_mir_rvalue_inner<'a>(cx: &impl Context<'a>, mir: &'a mir::Rvalue<'a>) -
         }
 
         mir::RvalueKind::IntBinaryArith { op, lhs, rhs, .. } => {
-            let lhs_val = cx.const_mir_rvalue(lhs.into());
-            let rhs_val = cx.const_mir_rvalue(rhs.into());
+            let lhs_val = const_eval(cx, lhs, frame);
+            let rhs_val = const_eval(cx, rhs, frame);
             if lhs_val.is_error() || rhs_val.is_error() {
                 return cx.intern_value(make_error(mir.ty));
             }
@@ -511,8 +624,8 @@ fn const_mir_rvalue_inner<'a>(cx: &impl Context<'a>, mir: &'a mir::Rvalue<'a>) -
         }
 
         mir::RvalueKind::IntComp { op, lhs, rhs, .. } => {
-            let lhs_val = cx.const_mir_rvalue(lhs.into());
-            let rhs_val = cx.const_mir_rvalue(rhs.into());
+            let lhs_val = const_eval(cx, lhs, frame);
+            let rhs_val = const_eval(cx, rhs, frame);
             if lhs_val.is_error() || rhs_val.is_error() {
                 return cx.intern_value(make_error(mir.ty));
             }
@@ -537,8 +650,7 @@ fn const_mir_rvalue_inner<'a>(cx: &impl Context<'a>, mir: &'a mir::Rvalue<'a>) -
             let mut result = BigInt::zero();
             for &value in values {
                 result <<= value.ty.simple_bit_vector(cx, value.span).size;
-                result |= cx
-                    .const_mir_rvalue(value.into())
+                result |= const_eval(cx, value, frame)
                     .get_int()
                     .expect("concat non-integer");
             }
@@ -546,7 +658,7 @@ fn const_mir_rvalue_inner<'a>(cx: &impl Context<'a>, mir: &'a mir::Rvalue<'a>) -
         }
 
         mir::RvalueKind::Repeat(count, value) => {
-            let value_const = cx.const_mir_rvalue(value.into());
+            let value_const = const_eval(cx, value, frame);
             if value_const.is_error() {
                 return cx.intern_value(make_error(mir.ty));
             }
@@ -559,18 +671,39 @@ fn const_mir_rvalue_inner<'a>(cx: &impl Context<'a>, mir: &'a mir::Rvalue<'a>) -
             cx.intern_value(make_int(mir.ty, result))
         }
 
+        // Local variables and arguments of a constant function.
+        mir::RvalueKind::Var(id) | mir::RvalueKind::Arg(id)
+            if frame.map_or(false, |f| f.values.borrow().contains_key(&id)) =>
+        {
+            frame.unwrap().values.borrow()[&id]
+        }
+
+        // Assignments to local variables and arguments of a constant function.
+        mir::RvalueKind::Assignment {
+            lvalue,
+            rvalue,
+            result,
+        } if frame.is_some() => {
+            let frame = frame.unwrap();
+            match const_store(cx, lvalue, rvalue, frame) {
+                Ok(()) => const_eval(cx, result, Some(frame)),
+                Err(()) => cx.intern_value(make_error(mir.ty)),
+            }
+        }
+
         mir::RvalueKind::Assignment { .. }
         | mir::RvalueKind::Var(_)
         | mir::RvalueKind::Port(_)
         | mir::RvalueKind::Arg(_)
         | mir::RvalueKind::IntfSignal(..)
+        | mir::RvalueKind::GenSignal { .. }
         | mir::RvalueKind::Intf(..) => {
             cx.emit(DiagBuilder2::error("value is not constant").span(mir.span));
             cx.intern_value(make_error(mir.ty))
         }
 
         mir::RvalueKind::Member { value, field } => {
-            let value_const = cx.const_mir_rvalue(value.into());
+            let value_const = const_eval(cx, value, frame);
             if value_const.is_error() {
                 return cx.intern_value(make_error(mir.ty));
             }
@@ -585,12 +718,15 @@ fn const_mir_rvalue_inner<'a>(cx: &impl Context<'a>, mir: &'a mir::Rvalue<'a>) -
             true_value,
             false_value,
         } => {
-            let cond_val = cx.const_mir_rvalue(cond.into());
-            let true_val = cx.const_mir_rvalue(true_value.into());
-            let false_val = cx.const_mir_rvalue(false_value.into());
+            // Only evaluate the branch that is taken, such that recursive
+            // constant functions terminate.
+            let cond_val = const_eval(cx, cond, frame);
+            if cond_val.is_error() {
+                return cx.intern_value(make_error(mir.ty));
+            }
             match cond_val.is_true() {
-                true => true_val,
-                false => false_val,
+                true => const_eval(cx, true_value, frame),
+                false => const_eval(cx, false_value, frame),
             }
         }
 
@@ -601,8 +737,8 @@ fn const_mir_rvalue_inner<'a>(cx: &impl Context<'a>, mir: &'a mir::Rvalue<'a>) -
             amount,
             ..
         } => {
-            let value_val = cx.const_mir_rvalue(value.into());
-            let amount_val = cx.const_mir_rvalue(amount.into());
+            let value_val = const_eval(cx, value, frame);
+            let amount_val = const_eval(cx, amount, frame);
             if value_val.is_error() || amount_val.is_error() {
                 return cx.intern_value(make_error(mir.ty));
             }
@@ -625,7 +761,7 @@ fn const_mir_rvalue_inner<'a>(cx: &impl Context<'a>, mir: &'a mir::Rvalue<'a>) -
         }
 
         mir::RvalueKind::Reduction { op, arg } => {
-            let arg_val = cx.const_mir_rvalue(arg.into());
+            let arg_val = const_eval(cx, arg, frame);
             if arg_val.is_error() {
                 return cx.intern_value(make_error(mir.ty));
             }
@@ -644,11 +780,11 @@ fn const_mir_rvalue_inner<'a>(cx: &impl Context<'a>, mir: &'a mir::Rvalue<'a>) -
             length,
             ..
         } => {
-            let inner_val = cx.const_mir_rvalue(value.into());
+            let inner_val = const_eval(cx, value, frame);
             if inner_val.is_error() {
                 return cx.intern_value(make_error(mir.ty));
             }
-            let base = match cx.const_mir_rvalue_int(Ref(base)) {
+            let base = match const_eval_int(cx, base, frame) {
                 Ok(x) => x.to_isize().expect("base out of bounds"),
                 _ => return cx.intern_value(make_error(mir.ty)),
             };
@@ -707,7 +843,7 @@ fn const_mir_rvalue_inner<'a>(cx: &impl Context<'a>, mir: &'a mir::Rvalue<'a>) -
         }
 
         // Pack a string into a vector.
-        mir::RvalueKind::PackString(value) => match cx.const_mir_rvalue_string(value.into()) {
+        mir::RvalueKind::PackString(value) => match const_eval_string(cx, value, frame) {
             Ok(v) => cx.intern_value(make_int(
                 mir.ty,
                 BigInt::from_bytes_be(num::bigint::Sign::Plus, v),
@@ -717,7 +853,7 @@ fn const_mir_rvalue_inner<'a>(cx: &impl Context<'a>, mir: &'a mir::Rvalue<'a>) -
 
         // Unpack a string from a vector.
         mir::RvalueKind::UnpackString(value) => {
-            let mut konst = match cx.const_mir_rvalue_int(value.into()) {
+            let mut konst = match const_eval_int(cx, value, frame) {
                 Ok(v) => v.clone(),
                 Err(()) => return cx.intern_value(make_error(mir.ty)),
             };
@@ -734,8 +870,8 @@ fn const_mir_rvalue_inner<'a>(cx: &impl Context<'a>, mir: &'a mir::Rvalue<'a>) -
         }
 
         mir::RvalueKind::StringComp { op, lhs, rhs, .. } => {
-            let lhs_val = cx.const_mir_rvalue(lhs.into());
-            let rhs_val = cx.const_mir_rvalue(rhs.into());
+            let lhs_val = const_eval(cx, lhs, frame);
+            let rhs_val = const_eval(cx, rhs, frame);
             if lhs_val.is_error() || rhs_val.is_error() {
                 return cx.intern_value(make_error(mir.ty));
             }
@@ -747,14 +883,406 @@ fn const_mir_rvalue_inner<'a>(cx: &impl Context<'a>, mir: &'a mir::Rvalue<'a>) -
             }
         }
 
-        // TODO(fschuiki): Fix this once we support constant function calls.
-        mir::RvalueKind::Call { .. } => cx.intern_value(make_error(mir.ty)),
+        mir::RvalueKind::Call { target, ref args } => {
+            match const_call(cx, mir, target, args, frame) {
+                Ok(v) => v,
+                Err(()) => cx.intern_value(make_error(mir.ty)),
+            }
+        }
 
         // Propagate tombstones.
         mir::RvalueKind::Error => cx.intern_value(make_error(mir.ty)),
     }
 }
 
+/// Evaluate a call to a constant function.
+///
+/// Binds the constant input arguments to the function's arguments and executes
+/// the statements in its body.
+fn const_call<'a>(
+    cx: &impl Context<'a>,
+    mir: &'a mir::Rvalue<'a>,
+    target: &'a ast::SubroutineDecl<'a>,
+    args: &[mir::CallArg<'a>],
+    frame: Option<&ConstFrame<'a>>,
+) -> Result<Value<'a>> {
+    let name = target.prototype.name;
+    if target.prototype.kind != ast::SubroutineKind::Func {
+        cx.emit(
+            DiagBuilder2::error(format!(
+                "task `{}` cannot be called in a constant expression",
+                name
+            ))
+            .span(mir.span),
+        );
+        return Err(());
+    }
+    let depth = frame.map(|f| f.depth + 1).unwrap_or(0);
+    if depth > MAX_CONST_CALL_DEPTH {
+        cx.emit(
+            DiagBuilder2::error(format!(
+                "constant function `{}` exceeds the maximum call depth of {}",
+                name, MAX_CONST_CALL_DEPTH
+            ))
+            .span(mir.span),
+        );
+        return Err(());
+    }
+
+    // Bind the argument values.
+    let decl_args = cx.canonicalize_func_args(Ref(target));
+    let callee = ConstFrame {
+        values: Default::default(),
+        depth,
+    };
+    for (decl_arg, arg) in decl_args.args.iter().zip(args) {
+        let value = match *arg {
+            mir::CallArg::Input(rv) => const_eval(cx, rv, frame),
+            _ => {
+                cx.emit(
+                    DiagBuilder2::error(format!(
+                        "constant function `{}` may only have input arguments",
+                        name
+                    ))
+                    .span(mir.span)
+                    .add_note("Argument declared here:")
+                    .span(decl_arg.span),
+                );
+                return Err(());
+            }
+        };
+        if value.is_error() {
+            return Err(());
+        }
+        callee.values.borrow_mut().insert(decl_arg.ast.id(), value);
+    }
+
+    // Execute the function body.
+    for item in &target.items {
+        if let ast::SubroutineItem::Stmt(stmt) = item {
+            match const_exec(cx, stmt.id(), mir.env, &callee)? {
+                ConstFlow::Next => (),
+                ConstFlow::Return(value) => return Ok(const_return(cx, mir, value)),
+                ConstFlow::Break | ConstFlow::Continue => {
+                    cx.emit(
+                        DiagBuilder2::error("break or continue statement outside of loop")
+                            .span(stmt.span()),
+                    );
+                    return Err(());
+                }
+            }
+        }
+    }
+    Ok(const_return(cx, mir, None))
+}
+
+/// Determine the result of a constant function call from its returned value.
+fn const_return<'a>(
+    cx: &impl Context<'a>,
+    mir: &'a mir::Rvalue<'a>,
+    value: Option<Value<'a>>,
+) -> Value<'a> {
+    match value {
+        Some(v) if v.is_error() => cx.intern_value(make_error(mir.ty)),
+        Some(v) => cx.intern_value(ValueData {
+            ty: mir.ty,
+            kind: v.kind.clone(),
+        }),
+        None => cx.type_default_value(mir.ty),
+    }
+}
+
+/// Execute a statement of a constant function.
+fn const_exec<'a>(
+    cx: &impl Context<'a>,
+    stmt_id: NodeId,
+    env: ParamEnv,
+    frame: &ConstFrame<'a>,
+) -> Result<ConstFlow<'a>> {
+    let hir = match cx.hir_of(stmt_id)? {
+        HirNode::Stmt(x) => x,
+        HirNode::VarDecl(decl) => {
+            let value = match decl.init {
+                Some(init) => const_eval(cx, cx.mir_rvalue(init, env), Some(frame)),
+                None => cx.type_default_value(cx.type_of(stmt_id, env)?),
+            };
+            if value.is_error() {
+                return Err(());
+            }
+            frame.values.borrow_mut().insert(stmt_id, value);
+            return Ok(ConstFlow::Next);
+        }
+        _ => unreachable!(),
+    };
+    match hir.kind {
+        hir::StmtKind::Null => Ok(ConstFlow::Next),
+        hir::StmtKind::Block(ref stmts) | hir::StmtKind::InlineGroup { ref stmts, .. } => {
+            for &stmt in stmts {
+                match const_exec(cx, stmt, env, frame)? {
+                    ConstFlow::Next => (),
+                    flow => return Ok(flow),
+                }
+            }
+            Ok(ConstFlow::Next)
+        }
+        hir::StmtKind::Assign {
+            lhs,
+            rhs,
+            kind: kind @ hir::AssignKind::Block(_),
+        } => {
+            let assign = cx.mir_assignment_from_procedural(stmt_id, lhs, rhs, env, hir.span, kind);
+            if assign.is_error() {
+                return Err(());
+            }
+            const_store(cx, assign.lhs, assign.rhs, frame)?;
+            Ok(ConstFlow::Next)
+        }
+        hir::StmtKind::Expr(expr) => {
+            const_eval_checked(cx, cx.mir_rvalue(expr, env), frame)?;
+            Ok(ConstFlow::Next)
+        }
+        hir::StmtKind::If {
+            cond,
+            main_stmt,
+            else_stmt,
+        } => {
+            let cond = const_eval_checked(cx, cx.mir_rvalue(cond, env), frame)?;
+            if cond.is_true() {
+                const_exec(cx, main_stmt, env, frame)
+            } else if let Some(else_stmt) = else_stmt {
+                const_exec(cx, else_stmt, env, frame)
+            } else {
+                Ok(ConstFlow::Next)
+            }
+        }
+        hir::StmtKind::Loop { ref kind, body } => {
+            const_exec_loop(cx, stmt_id, hir, kind, body, env, frame)
+        }
+        hir::StmtKind::Return(None) => Ok(ConstFlow::Return(None)),
+        hir::StmtKind::Return(Some(expr)) => {
+            let value = const_eval_checked(cx, cx.mir_rvalue(expr, env), frame)?;
+            Ok(ConstFlow::Return(Some(value)))
+        }
+        hir::StmtKind::Break => Ok(ConstFlow::Break),
+        hir::StmtKind::Continue => Ok(ConstFlow::Continue),
+        _ => const_unsupported(cx, hir),
+    }
+}
+
+/// Execute a loop statement of a constant function.
+fn const_exec_loop<'a>(
+    cx: &impl Context<'a>,
+    stmt_id: NodeId,
+    hir: &hir::Stmt,
+    kind: &hir::LoopKind,
+    body: NodeId,
+    env: ParamEnv,
+    frame: &ConstFrame<'a>,
+) -> Result<ConstFlow<'a>> {
+    // Evaluates the condition of the loop, if any.
+    let check = |cond: NodeId| -> Result<bool> {
+        Ok(const_eval_checked(cx, cx.mir_rvalue(cond, env), frame)?.is_true())
+    };
+
+    let mut remaining = match *kind {
+        hir::LoopKind::Repeat(count) => {
+            let count = const_eval_checked(cx, cx.mir_rvalue(count, env), frame)?;
+            Some(count.get_int().and_then(|x| x.to_usize()).unwrap_or(0))
+        }
+        hir::LoopKind::For(init, ..) => {
+            match const_exec(cx, init, env, frame)? {
+                ConstFlow::Next => (),
+                flow => return Ok(flow),
+            }
+            None
+        }
+        hir::LoopKind::Forever | hir::LoopKind::While(_) | hir::LoopKind::Do(_) => None,
+        hir::LoopKind::Foreach(..) => return const_unsupported(cx, hir),
+    };
+
+    for _ in 0..MAX_CONST_LOOP_ITERATIONS {
+        // Check whether the loop should be entered.
+        let enter = match *kind {
+            hir::LoopKind::Repeat(_) => remaining.map_or(false, |n| n > 0),
+            hir::LoopKind::While(cond) | hir::LoopKind::For(_, cond, _) => check(cond)?,
+            _ => true,
+        };
+        if !enter {
+            return Ok(ConstFlow::Next);
+        }
+
+        // Execute the body.
+        match const_exec(cx, body, env, frame)? {
+            ConstFlow::Next | ConstFlow::Continue => (),
+            ConstFlow::Break => return Ok(ConstFlow::Next),
+            flow @ ConstFlow::Return(_) => return Ok(flow),
+        }
+
+        // Advance to the next iteration.
+        match *kind {
+            hir::LoopKind::Repeat(_) => remaining = remaining.map(|n| n - 1),
+            hir::LoopKind::Do(cond) => {
+                if !check(cond)? {
+                    return Ok(ConstFlow::Next);
+                }
+            }
+            hir::LoopKind::For(_, _, step) => {
+                const_eval_checked(cx, cx.mir_rvalue(step, env), frame)?;
+            }
+            _ => (),
+        }
+    }
+
+    cx.emit(
+        DiagBuilder2::error(format!(
+            "loop in constant function exceeds {} iterations",
+            MAX_CONST_LOOP_ITERATIONS
+        ))
+        .span(cx.span(stmt_id)),
+    );
+    Err(())
+}
+
+/// Determine whether a `for` loop executes its body at least once.
+///
+/// This can only be decided during elaboration if the loop declares its
+/// variables in the header with constant initial values, and its condition
+/// depends only on these variables and constants. Returns `None` otherwise.
+pub(crate) fn for_loop_enters_body<'a>(
+    cx: &impl Context<'a>,
+    init: NodeId,
+    cond: NodeId,
+    env: ParamEnv,
+) -> Option<bool> {
+    let decls = match cx.hir_of(init).ok()? {
+        HirNode::Stmt(hir::Stmt {
+            kind: hir::StmtKind::InlineGroup { stmts, .. },
+            ..
+        }) => stmts,
+        _ => return None,
+    };
+    let frame = ConstFrame {
+        values: Default::default(),
+        depth: 0,
+    };
+    for &decl_id in decls {
+        let decl_init = match cx.hir_of(decl_id).ok()? {
+            HirNode::VarDecl(decl) => decl.init?,
+            _ => return None,
+        };
+        let mir = cx.mir_rvalue(decl_init, env);
+        if !mir.is_const() {
+            return None;
+        }
+        let value = cx.const_mir_rvalue(Ref(mir));
+        if value.is_error() {
+            return None;
+        }
+        frame.values.borrow_mut().insert(decl_id, value);
+    }
+
+    // Only evaluate the condition if this cannot run into values that are
+    // not known during elaboration.
+    let mir = cx.mir_rvalue(cond, env);
+    let mut check = FrameOnlyReads {
+        frame: &frame,
+        ok: true,
+    };
+    mir.walk(&mut check);
+    if !check.ok {
+        return None;
+    }
+    let value = const_eval(cx, mir, Some(&frame));
+    if value.is_error() {
+        None
+    } else {
+        Some(value.is_true())
+    }
+}
+
+/// A visitor that checks whether an MIR rvalue only reads the local variables
+/// of a constant function frame, besides constants.
+struct FrameOnlyReads<'f, 'a> {
+    frame: &'f ConstFrame<'a>,
+    ok: bool,
+}
+
+impl<'a> mir::Visitor<'a> for FrameOnlyReads<'_, 'a> {
+    fn pre_visit_rvalue(&mut self, mir: &mir::Rvalue) -> bool {
+        match mir.kind {
+            mir::RvalueKind::Var(id) if self.frame.values.borrow().contains_key(&id) => false,
+            mir::RvalueKind::Assignment { .. }
+            | mir::RvalueKind::Var(_)
+            | mir::RvalueKind::Port(_)
+            | mir::RvalueKind::Arg(_)
+            | mir::RvalueKind::IntfSignal(..)
+            | mir::RvalueKind::GenSignal { .. }
+            | mir::RvalueKind::Intf(..) => {
+                self.ok = false;
+                false
+            }
+            _ => true,
+        }
+    }
+}
+
+/// Evaluate an MIR rvalue within a constant function, mapping errors to `Err`.
+fn const_eval_checked<'a>(
+    cx: &impl Context<'a>,
+    mir: &'a mir::Rvalue<'a>,
+    frame: &ConstFrame<'a>,
+) -> Result<Value<'a>> {
+    let value = const_eval(cx, mir, Some(frame));
+    if value.is_error() {
+        Err(())
+    } else {
+        Ok(value)
+    }
+}
+
+/// Assign a value to a local variable or argument of a constant function.
+fn const_store<'a>(
+    cx: &impl Context<'a>,
+    lvalue: &'a mir::Lvalue<'a>,
+    rvalue: &'a mir::Rvalue<'a>,
+    frame: &ConstFrame<'a>,
+) -> Result<()> {
+    let id = match lvalue.kind {
+        mir::LvalueKind::Var(id) | mir::LvalueKind::Arg(id)
+            if frame.values.borrow().contains_key(&id) =>
+        {
+            id
+        }
+        mir::LvalueKind::Error => return Err(()),
+        _ => {
+            cx.emit(
+                DiagBuilder2::error(format!(
+                    "`{}` cannot be assigned in a constant function",
+                    lvalue.span.extract()
+                ))
+                .span(lvalue.span)
+                .add_note("Only local variables and arguments can be assigned."),
+            );
+            return Err(());
+        }
+    };
+    let value = const_eval_checked(cx, rvalue, frame)?;
+    frame.values.borrow_mut().insert(id, value);
+    Ok(())
+}
+
+/// Report a statement that cannot be executed in a constant function.
+fn const_unsupported<'a, T>(cx: &impl Context<'a>, hir: &hir::Stmt) -> Result<T> {
+    cx.emit(
+        DiagBuilder2::error(format!(
+            "{} is not allowed in a constant function",
+            hir.desc_full()
+        ))
+        .span(hir.human_span()),
+    );
+    Err(())
+}
+
 fn const_unary_bitwise_int<'gcx>(
     _cx: &impl Context<'gcx>,
     ty: SbvType,
@@ -0,0 +1,14 @@
+// RUN: moore -e foo --format=mlir-native %s | FileCheck %s
+
+// Continuous assignments to an element of an array of nets only drive that
+// element.
+
+// CHECK-LABEL: llhd.entity @foo (
+module foo (input logic [7:0] x);
+    // CHECK: [[MEM:%.+]] = llhd.sig "mem"
+    wire [7:0] mem [4];
+    // CHECK: [[IDX:%.+]] = hw.constant 3
+    // CHECK: [[ELEM:%.+]] = llhd.sig.array_get [[MEM]]{{\[}}[[IDX]]{{\]}}
+    // CHECK: llhd.drv [[ELEM]], {{%.+}} after
+    assign mem[3] = x;
+endmodule
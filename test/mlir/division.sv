@@ -0,0 +1,55 @@
+// RUN: moore -e foo --format=mlir-native %s | FileCheck %s
+
+// CHECK-LABEL: func @SignedDiv(
+// CHECK-SAME:    [[X:%.+]]: i32, [[Y:%.+]]: i32
+// CHECK-SAME:  ) -> i32 {
+// CHECK-NEXT:    [[TMP:%.+]] = comb.divs [[X]], [[Y]] : i32
+// CHECK-NEXT:    return [[TMP]]
+function int SignedDiv(int x, int y);
+    return x / y;
+endfunction
+
+// CHECK-LABEL: func @UnsignedDiv(
+// CHECK-SAME:    [[X:%.+]]: i32, [[Y:%.+]]: i32
+// CHECK-SAME:  ) -> i32 {
+// CHECK-NEXT:    [[TMP:%.+]] = comb.divu [[X]], [[Y]] : i32
+// CHECK-NEXT:    return [[TMP]]
+function int unsigned UnsignedDiv(int unsigned x, int unsigned y);
+    return x / y;
+endfunction
+
+// CHECK-LABEL: func @SignedMod(
+// CHECK-SAME:    [[X:%.+]]: i32, [[Y:%.+]]: i32
+// CHECK-SAME:  ) -> i32 {
+// CHECK-NEXT:    [[TMP:%.+]] = comb.mods [[X]], [[Y]] : i32
+// CHECK-NEXT:    return [[TMP]]
+function int SignedMod(int x, int y);
+    return x % y;
+endfunction
+
+// A single unsigned operand makes the entire operation unsigned.
+// CHECK-LABEL: func @MixedMod(
+// CHECK-SAME:    [[X:%.+]]: i32, [[Y:%.+]]: i32
+// CHECK-SAME:  ) -> i32 {
+// CHECK-NEXT:    [[TMP:%.+]] = comb.modu [[X]], [[Y]] : i32
+// CHECK-NEXT:    return [[TMP]]
+function int unsigned MixedMod(int x, int unsigned y);
+    return x % y;
+endfunction
+
+// CHECK-LABEL: func @ConstDiv() -> i32 {
+// CHECK-NEXT:    [[TMP:%.+]] = hw.constant -3 : i32
+// CHECK-NEXT:    return [[TMP]]
+function int ConstDiv;
+    return -7 / 2;
+endfunction
+
+// CHECK-LABEL: func @ConstUnsignedDiv() -> i32 {
+// CHECK-NEXT:    [[TMP:%.+]] = hw.constant 3 : i32
+// CHECK-NEXT:    return [[TMP]]
+function int ConstUnsignedDiv;
+    return 7 / 2;
+endfunction
+
+module foo;
+endmodule
@@ -9,6 +9,7 @@ function void ShiftExpressions(bit signed [21:0] x, bit [5:0] y);
 
     // CHECK: moore.mir.shr %{{.+}}, %{{.+}} : !moore.packed<range<bit, 21:0>>, !moore.packed<range<bit, 5:0>>
     // CHECK: moore.mir.shr arithmetic %{{.+}}, %{{.+}} : !moore.packed<range<bit, 21:0>>, !moore.packed<range<bit, 5:0>>
+    // CHECK: comb.shrs {{%.+}}, {{%.+}}
     bit [21:0] c = x >> y;
     bit signed [21:0] d = x >>> y;
 endfunction
@@ -20,5 +21,95 @@ function bit [6:0] Concat(bit [3:0] x, bit [1:0] y, bit z);
     return {x, y, z};
 endfunction
 
+// Concatenations are MSB-first, such that `x` ends up in the upper bits.
+// CHECK-LABEL: func @ConcatOrder(
+// CHECK-SAME: [[X:%.+]]: i4, [[Y:%.+]]: i4) -> i2 {
+function bit [1:0] ConcatOrder(bit [3:0] x, bit [3:0] y);
+    // CHECK: [[XS:%.+]] = comb.extract {{%.+}} from {{[0-9]+}} : (i4) -> i2
+    // CHECK: [[XC:%.+]] = {{.+}}[[XS]]
+    // CHECK: [[YS:%.+]] = comb.extract {{%.+}} from {{[0-9]+}} : (i4) -> i2
+    // CHECK: [[YC:%.+]] = {{.+}}[[YS]]
+    // CHECK: moore.mir.concat [[XC]], [[YC]] : (!moore.packed<range<bit, 1:0>>, !moore.packed<range<bit, 1:0>>) -> !moore.packed<range<bit, 3:0>>
+    bit [3:0] z = {x[1:0], y[1:0]};
+    // CHECK: comb.extract {{%.+}} from {{[0-9]+}} : (i4) -> i2
+    return z[3:2];
+endfunction
+
+// CHECK-LABEL: func @Replicate(
+// CHECK-SAME: [[X:%.+]]: i4) -> i12 {
+function bit [11:0] Replicate(bit [3:0] x);
+    // CHECK: comb.replicate {{%.+}} : (i4) -> i12
+    return {(1 + 2){x}};
+endfunction
+
+// Repeat patterns of a single value are lowered to a replication.
+// CHECK-LABEL: func @RepeatPattern(
+// CHECK-SAME: [[X:%.+]]: i1) -> i4 {
+function bit [3:0] RepeatPattern(bit x);
+    // CHECK: comb.replicate {{%.+}} : (i1) -> i4
+    return '{4{x}};
+endfunction
+
+// CHECK-LABEL: func @ReplicateConcat(
+// CHECK-SAME: [[X:%.+]]: i4, [[Y:%.+]]: i2) -> i18 {
+function bit [17:0] ReplicateConcat(bit [3:0] x, bit [1:0] y);
+    // CHECK: moore.mir.concat %{{.+}}, %{{.+}} : (!moore.packed<range<bit, 3:0>>, !moore.packed<range<bit, 1:0>>) -> !moore.packed<range<bit, 5:0>>
+    // CHECK: comb.replicate {{%.+}} : (i6) -> i18
+    return {3{x, y}};
+endfunction
+
+// Packed structs are compared as their full-width bit vectors.
+// CHECK-LABEL: func @PackedStructEq(
+function bit PackedStructEq(pkg::pair_t x, pkg::pair_t y);
+    // CHECK: comb.icmp eq {{%.+}}, {{%.+}} : i6
+    return x == y;
+endfunction
+
+// Reductions combine all bits in a single variadic operation.
+// CHECK-LABEL: func @ReductionAnd(
+function bit ReductionAnd(bit [3:0] x);
+    // CHECK: comb.and {{%.+}}, {{%.+}}, {{%.+}}, {{%.+}} : i1
+    return &x;
+endfunction
+
+// An XOR reduction computes the parity of its operand.
+// CHECK-LABEL: func @ReductionXor(
+// CHECK-SAME: [[X:%.+]]: i4) -> i1 {
+function bit ReductionXor(bit [3:0] x);
+    // CHECK: [[RESULT:%.+]] = comb.parity {{%.+}} : i4
+    // CHECK: return [[RESULT]] : i1
+    return ^x;
+endfunction
+
+// Comparisons yield a single bit regardless of the operand width.
+// CHECK-LABEL: func @CompareWidth(
+// CHECK-SAME: [[X:%.+]]: i8, [[Y:%.+]]: i8) -> i1 {
+function bit CompareWidth(bit [7:0] x, bit [7:0] y);
+    // CHECK: [[RESULT:%.+]] = comb.icmp ult {{%.+}}, {{%.+}} : i8
+    // CHECK: return [[RESULT]] : i1
+    return x < y;
+endfunction
+
+// Variable bit-selects shift the selected bit into the LSB.
+// CHECK-LABEL: func @DynamicBitSelect(
+// CHECK-SAME: [[X:%.+]]: i8, [[I:%.+]]: i3) -> i1 {
+function bit DynamicBitSelect(bit [7:0] x, bit [2:0] i);
+    // CHECK: [[SHIFTED:%.+]] = comb.shru {{%.+}}, {{%.+}} : i8
+    // CHECK: comb.extract [[SHIFTED]] from 0 : (i8) -> i1
+    return x[i];
+endfunction
+
+// Powers of two are lowered to a left shift.
+// CHECK-LABEL: func @PowerOfTwo(
+// CHECK-SAME: [[Y:%.+]]: i32) -> i32 {
+function int PowerOfTwo(int y);
+    // CHECK: comb.shl {{%.+}}, {{%.+}} : i32
+    return 4 ** y;
+endfunction
+
+package pkg;
+    typedef struct packed { bit [3:0] a; bit [1:0] b; } pair_t;
+endpackage
+
 module foo;
 endmodule
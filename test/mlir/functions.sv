@@ -24,6 +24,15 @@ function int InputArgs(int x, int y);
     return x + y;
 endfunction
 
+// CHECK-LABEL: func @PackedArrayReturn(
+// CHECK-SAME:    [[X:%.+]]: !hw.array<4xi8>
+// CHECK-SAME:  ) -> !hw.array<4xi8> {
+// CHECK-NEXT:    return [[X]] : !hw.array<4xi8>
+// CHECK-NEXT:  }
+function logic [3:0][7:0] PackedArrayReturn(logic [3:0][7:0] x);
+    return x;
+endfunction
+
 // CHECK-LABEL: func @OutputArgs(
 // CHECK-SAME:    [[X:%.+]]: !llhd.ptr<i32>, [[Y:%.+]]: !llhd.ptr<i32>
 // CHECK-SAME:  ) {
@@ -122,5 +131,18 @@ function void Calls;
     // CHECK-NEXT: return
 endfunction
 
+// CHECK-LABEL: func @ConstLocal(
+// CHECK-SAME:    [[A:%.+]]: i32
+// CHECK-SAME:  ) -> i32 {
+// CHECK:         [[TMP:%.+]] = comb.add [[A]], {{%.+}}
+// CHECK-NEXT:    [[C:%.+]] = llhd.var [[TMP]]
+// CHECK-NEXT:    [[TMP:%.+]] = llhd.load [[C]]
+// CHECK-NEXT:    return [[TMP]]
+// CHECK-NEXT:  }
+function int ConstLocal(int a);
+    const int c = a + 1;
+    return c;
+endfunction
+
 module foo;
 endmodule
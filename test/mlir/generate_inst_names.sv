@@ -0,0 +1,22 @@
+// RUN: moore -e Foo --format=mlir-native %s | FileCheck %s
+
+// CHECK-LABEL: llhd.entity @Foo
+module Foo;
+  // CHECK: llhd.inst "g[0].u" @Bar
+  // CHECK: llhd.inst "g[1].u" @Bar
+  // CHECK: llhd.inst "g[2].u" @Bar
+  for (genvar i = 0; i < 3; i++) begin : g
+    Bar u();
+  end
+
+  // CHECK: llhd.inst "h[0].k[1].u" @Bar
+  // CHECK: llhd.inst "h[1].k[1].u" @Bar
+  for (genvar i = 0; i < 2; i++) begin : h
+    for (genvar j = 1; j < 2; j++) begin : k
+      Bar u();
+    end
+  end
+endmodule
+
+module Bar;
+endmodule
@@ -0,0 +1,12 @@
+// RUN: moore -e Foo --format=mlir-native %s | FileCheck %s
+
+// CHECK-LABEL: llhd.entity @Foo
+module Foo;
+  // CHECK: llhd.sig "g[0].bus" {{%.+}} : i8
+  // CHECK: llhd.sig "g[1].bus" {{%.+}} : i8
+  // CHECK: llhd.sig "g[2].bus" {{%.+}} : i8
+  for (genvar i = 0; i < 3; i++) begin : g
+    wire [7:0] bus;
+    assign bus = i;
+  end
+endmodule
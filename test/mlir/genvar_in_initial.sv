@@ -0,0 +1,20 @@
+// RUN: moore -e foo --format=mlir-native %s | FileCheck %s
+
+// Genvar references in procedural code within a generate-for are replaced by
+// the value of the genvar in each iteration.
+
+// CHECK-LABEL: llhd.proc @foo.initial.
+// CHECK: hw.constant 0 : i32
+// CHECK: llhd.halt
+// CHECK-LABEL: llhd.proc @foo.initial.
+// CHECK: hw.constant 1 : i32
+// CHECK: llhd.halt
+// CHECK-LABEL: llhd.proc @foo.initial.
+// CHECK: hw.constant 2 : i32
+// CHECK: llhd.halt
+module foo;
+    for (genvar i = 0; i < 3; i++) begin : g
+        int x;
+        initial x = i;
+    end
+endmodule
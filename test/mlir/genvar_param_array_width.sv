@@ -0,0 +1,13 @@
+// RUN: moore -e foo --format=mlir-native %s | FileCheck %s
+
+// A genvar indexing into a parameter array folds into the type width.
+// CHECK-LABEL: llhd.entity @foo
+// CHECK: llhd.sig "x" {{.+}} : i2
+// CHECK: llhd.sig "x" {{.+}} : i3
+// CHECK: llhd.sig "x" {{.+}} : i5
+module foo;
+    localparam int W [3] = '{2, 3, 5};
+    for (genvar i = 0; i < 3; i++) begin : g
+        logic [W[i]-1:0] x;
+    end
+endmodule
@@ -1,4 +1,4 @@
-// RUN: moore %s -e A -e C -e N1 -e N2 -e N3
+// RUN: moore %s -e A -e C -e N1 -e N2 -e N3 -e T
 
 module X (input int a, output int b);
 endmodule
@@ -80,3 +80,17 @@ endmodule
 // CHECK:     %foo.b.default = sig i32 %1
 // CHECK:     inst @X.param1 (i32$ %a) -> (i32$ %foo.b.default)
 // CHECK: }
+
+module T;
+    int a, b, c;
+    X foo(1 ? a : b, c);
+endmodule
+
+// Ternaries with a constant condition fold to the selected connection.
+// CHECK: entity @T () -> () {
+// CHECK:     %0 = const i32 0
+// CHECK:     %a = sig i32 %0
+// CHECK:     %b = sig i32 %0
+// CHECK:     %c = sig i32 %0
+// CHECK:     inst @X.param1 (i32$ %a) -> (i32$ %c)
+// CHECK: }
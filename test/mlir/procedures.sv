@@ -0,0 +1,29 @@
+// RUN: moore -e foo --format=mlir-native %s | FileCheck %s
+
+// `initial` procedures run once and halt.
+// CHECK-LABEL: llhd.proc @foo.initial.
+// CHECK-NOT:     cf.br
+// CHECK:         llhd.halt
+// CHECK-NEXT:  }
+
+// `final` procedures wait for the end of the simulation, run once, and halt.
+// CHECK-LABEL: llhd.proc @foo.final.
+// CHECK:         llhd.wait for {{%.+}}, [[BB_BODY:\^.+]]
+// CHECK-NEXT:  [[BB_BODY]]:
+// CHECK-NOT:     cf.br
+// CHECK:         llhd.halt
+// CHECK-NEXT:  }
+
+// `always` procedures jump back to their head.
+// CHECK-LABEL: llhd.proc @foo.always.
+// CHECK-NEXT:    cf.br [[BB_HEAD:\^.+]]
+// CHECK-NEXT:  [[BB_HEAD]]:
+// CHECK:         cf.br [[BB_HEAD]]
+// CHECK-NEXT:  }
+
+module foo;
+    int x;
+    initial x = 1;
+    final x = 2;
+    always #1ns x = 3;
+endmodule
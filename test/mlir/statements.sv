@@ -18,6 +18,17 @@ function int UnreachableReturns;
     return 9001;
 endfunction
 
+// CHECK-LABEL: func @ReturnInLoop() -> i32 {
+function int ReturnInLoop;
+    // CHECK-NEXT: br ^[[LOOP:.+]]
+    forever begin
+        // CHECK-NEXT: ^[[LOOP]]:
+        // CHECK-NEXT:   [[TMP:%.+]] = hw.constant 42 : i32
+        // CHECK-NEXT:   return [[TMP]]
+        return 42;
+    end
+endfunction
+
 // CHECK-LABEL: func @BreakStmt() {
 function void BreakStmt;
     // CHECK-NEXT: br ^[[LOOP:.+]]
@@ -84,5 +95,104 @@ function void ContinueStmtWithUnreachableCode;
     // CHECK-NEXT:   return
 endfunction
 
+// CHECK-LABEL: func @CaseStmtConstSelector() {
+function void CaseStmtConstSelector;
+    // CHECK-NEXT: [[TMP:%.+]] = hw.constant
+    // CHECK-NEXT: [[VAR:%.+]] = llhd.var [[TMP]]
+    int x;
+    // CHECK-NEXT: [[TMP:%.+]] = hw.constant 2 : i32
+    // CHECK-NEXT: llhd.store [[VAR]], [[TMP]]
+    // CHECK-NEXT: return
+    case (2'd1)
+        0: x = 1;
+        1: x = 2;
+        default: x = 3;
+    endcase
+endfunction
+
+// Labels are compared at the width of the widest operand, such that `5` does
+// not match the 2-bit selector.
+// CHECK-LABEL: func @CaseStmtWideLabel() {
+function void CaseStmtWideLabel;
+    // CHECK-NEXT: [[TMP:%.+]] = hw.constant
+    // CHECK-NEXT: [[VAR:%.+]] = llhd.var [[TMP]]
+    int x;
+    // CHECK-NEXT: [[TMP:%.+]] = hw.constant 3 : i32
+    // CHECK-NEXT: llhd.store [[VAR]], [[TMP]]
+    // CHECK-NEXT: return
+    case (2'd1)
+        5: x = 2;
+        default: x = 3;
+    endcase
+endfunction
+
+// CHECK-LABEL: func @CaseStmtNonConstLabel(
+function int CaseStmtNonConstLabel(logic [1:0] sel);
+    // CHECK: comb.icmp eq
+    // CHECK: comb.icmp eq
+    case (1'b1)
+        sel[0]: return 1;
+        sel[1]: return 2;
+        default: return 0;
+    endcase
+endfunction
+
+// CHECK-LABEL: func @CaseZStmt(
+function int CaseZStmt(logic [3:0] x);
+    // CHECK: [[LHS:%.+]] = comb.and
+    // CHECK: [[RHS:%.+]] = comb.and
+    // CHECK: comb.icmp eq [[LHS]], [[RHS]] : i4
+    casez (x)
+        4'b1??0: return 1;
+        default: return 0;
+    endcase
+endfunction
+
+// CHECK-LABEL: func @CaseXStmt(
+function int CaseXStmt(logic [3:0] x);
+    // CHECK: [[LHS:%.+]] = comb.and
+    // CHECK: [[RHS:%.+]] = comb.and
+    // CHECK: comb.icmp eq [[LHS]], [[RHS]] : i4
+    casex (x)
+        4'b1x?0: return 1;
+        default: return 0;
+    endcase
+endfunction
+
+// CHECK-LABEL: func @ImmediateAssert(
+function void ImmediateAssert(int x);
+    int y;
+    // CHECK: cf.cond_br {{%.+}}, [[PASS:\^.+]], [[FAIL:\^.+]]
+    // CHECK-NEXT: [[PASS]]:
+    // CHECK-NEXT:   cf.br [[EXIT:\^.+]]
+    // CHECK-NEXT: [[FAIL]]:
+    // CHECK-NEXT:   [[TMP:%.+]] = hw.constant 1 : i32
+    // CHECK-NEXT:   llhd.store {{%.+}}, [[TMP]]
+    // CHECK-NEXT:   cf.br [[EXIT]]
+    assert (x == 0) else y = 1;
+endfunction
+
+// CHECK-LABEL: func @ForeachLoop(
+function int ForeachLoop(logic [3:0] x);
+    int n;
+    // CHECK: [[FIRST:%.+]] = hw.constant 3 : i32
+    // CHECK-NEXT: [[I:%.+]] = llhd.var [[FIRST]]
+    // CHECK-NEXT: cf.br [[BODY:\^.+]]
+    // CHECK-NEXT: [[BODY]]:
+    // CHECK: llhd.load [[I]]
+    // CHECK: cf.br [[STEP:\^.+]]
+    // CHECK-NEXT: [[STEP]]:
+    // CHECK-NEXT: [[TMP:%.+]] = llhd.load [[I]]
+    // CHECK-NEXT: [[LAST:%.+]] = hw.constant 0 : i32
+    // CHECK-NEXT: [[CMP:%.+]] = comb.icmp ne [[TMP]], [[LAST]]
+    // CHECK-NEXT: [[ONE:%.+]] = hw.constant 1 : i32
+    // CHECK-NEXT: [[NEXT:%.+]] = comb.sub [[TMP]], [[ONE]]
+    // CHECK-NEXT: llhd.store [[I]], [[NEXT]]
+    // CHECK-NEXT: cf.cond_br [[CMP]], [[BODY]], [[EXIT:\^.+]]
+    foreach (x[i])
+        n += i;
+    return n;
+endfunction
+
 module foo;
 endmodule
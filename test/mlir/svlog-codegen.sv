@@ -31,8 +31,12 @@ module Foo (
 
   for (genvar i = 0; i < 2; i++)
     Bar manyBar();
-  // CHECK: llhd.inst "manyBar" @Bar
-  // CHECK: llhd.inst "manyBar_0" @Bar
+  // CHECK: llhd.inst "genblk1[0].manyBar" @Bar
+  // CHECK: llhd.inst "genblk1[1].manyBar" @Bar
+
+  for (genvar j = 0; j < 1; j++)
+    Bar moreBar();
+  // CHECK: llhd.inst "genblk2[0].moreBar" @Bar
 
   //===--------------------------------------------------------------------===//
   // Procedures
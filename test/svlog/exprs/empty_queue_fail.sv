@@ -0,0 +1,8 @@
+// RUN: moore %s -e foo
+// FAIL
+
+module foo;
+    int x;
+    initial x = {};
+    // CHECK-ERR: error: empty queue `{}` cannot be used as `int`
+endmodule
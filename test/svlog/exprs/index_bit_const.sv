@@ -0,0 +1,12 @@
+// RUN: moore %s -e foo -O0
+module foo (input logic [7:0] data, output logic y);
+    // A constant index extracts the bit directly.
+    assign y = data[3];
+endmodule
+
+// CHECK: entity @foo (i8$ %data) -> (i1$ %y) {
+// CHECK:     %0 = const time 0s 1e
+// CHECK:     %data.prb = prb i8$ %data
+// CHECK-NEXT:     %1 = exts i1, i8 %data.prb, 3, 1
+// CHECK-NEXT:     drv i1$ %y, %1, %0
+// CHECK: }
@@ -0,0 +1,15 @@
+// RUN: moore %s -e foo -O0
+module foo (input logic [7:0] data, input logic [2:0] i, output logic y);
+    // A variable index shifts the selected bit into the LSB and extracts it.
+    assign y = data[i];
+endmodule
+
+// CHECK: entity @foo (i8$ %data, i3$ %i) -> (i1$ %y) {
+// CHECK:     %0 = const time 0s 1e
+// CHECK:     %data.prb = prb i8$ %data
+// CHECK-NEXT:     %i.prb = prb i3$ %i
+// CHECK-NEXT:     %1 = const i8 0
+// CHECK-NEXT:     %2 = shr i8 %data.prb, i8 %1, i3 %i.prb
+// CHECK-NEXT:     %3 = exts i1, i8 %2, 0, 1
+// CHECK-NEXT:     drv i1$ %y, %3, %0
+// CHECK: }
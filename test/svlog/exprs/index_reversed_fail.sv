@@ -0,0 +1,14 @@
+// RUN: moore %s -e foo
+// FAIL
+
+module foo;
+    logic [7:0] x;
+    logic [0:7] y;
+    logic [2:0] a, b;
+    assign a = x[5:3];
+    assign b = y[3:5];
+    assign a = x[3:5];
+    // CHECK-ERR: error: part-select `x[3:5]` is reversed
+    assign b = y[5:3];
+    // CHECK-ERR: error: part-select `y[5:3]` is reversed
+endmodule
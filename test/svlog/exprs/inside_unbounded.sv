@@ -0,0 +1,7 @@
+// RUN: moore %s -e foo
+module foo;
+  logic [3:0] x;
+  bit y, z;
+  initial y = x inside {[4'd12:$]};
+  initial z = x inside {[$:4'd3], 4'd7};
+endmodule
@@ -0,0 +1,20 @@
+// RUN: moore %s -e foo -Vtypes
+// FAIL
+
+module foo;
+    int q [$];
+    int n, x;
+
+    initial begin
+        q.push_back(42);
+        // CHECK: 9: type(q.push_back(42)) = void
+        // CHECK: 9: type_context(42) = int
+        n = q.size();
+        // CHECK: 12: type(q.size()) = int
+        x = q.pop_front();
+        // CHECK: 14: type(q.pop_front()) = int
+    end
+
+    // Queues cannot be mapped to hardware yet.
+    // CHECK-ERR: error: unsupported: `q` of type `int $ [$]` has no fixed size
+endmodule
@@ -0,0 +1,8 @@
+// RUN: moore %s -e foo -Vtypes
+// FAIL
+
+module foo;
+    int q [$];
+    initial q.shuffle_all();
+    // CHECK-ERR: error: unknown method `shuffle_all`
+endmodule
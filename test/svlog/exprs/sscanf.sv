@@ -0,0 +1,15 @@
+// RUN: moore %s -e foo -Vtypes
+// FAIL
+
+module foo;
+    bit [63:0] line;
+    int n, a, b;
+
+    initial begin
+        n = $sscanf(line, "%d %d", a, b);
+        // CHECK: 9: type($sscanf(line, "%d %d", a, b)) = int
+    end
+
+    // Scanning cannot be mapped to hardware.
+    // CHECK-ERR: error: unsupported: `$sscanf`
+endmodule
@@ -0,0 +1,12 @@
+// RUN: moore %s -e foo -O0
+
+module foo;
+    bit [7:0] a = {>>{4'hA, 4'h5}};
+    // CHECK: %0 = const i8 165
+    bit [7:0] b = {<<{8'b1100_0001}};
+    // CHECK: %1 = const i8 131
+    bit [7:0] c = {<<4{8'hA5}};
+    // CHECK: %2 = const i8 90
+    bit [7:0] d = {<<3{8'b110_101_11}};
+    // CHECK: %3 = const i8 235
+endmodule
@@ -0,0 +1,9 @@
+// RUN: moore %s -e foo --warn-width
+// Assigning a narrower value to a wider target extends it.
+
+module foo;
+    logic [3:0] a;
+    logic [7:0] b;
+    always_comb b = a;
+    // CHECK-ERR: warning: assignment implicitly extends `a` from 4 to 8 bits
+endmodule
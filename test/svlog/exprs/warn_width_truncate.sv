@@ -0,0 +1,9 @@
+// RUN: moore %s -e foo --warn-width
+// Assigning a wider value to a narrower target truncates it.
+
+module foo;
+    logic [7:0] a;
+    logic [3:0] b;
+    assign b = a;
+    // CHECK-ERR: warning: assignment implicitly truncates `a` from 8 to 4 bits
+endmodule
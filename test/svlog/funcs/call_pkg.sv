@@ -0,0 +1,13 @@
+// RUN: moore %s -e foo
+
+package pkg;
+    function automatic int add(int a, int b);
+        return a + b;
+    endfunction
+endpackage
+
+module foo (output int y, output int z);
+    import pkg::*;
+    initial y = pkg::add(20, 22);
+    initial z = add(1, 2);
+endmodule
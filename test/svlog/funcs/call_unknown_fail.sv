@@ -0,0 +1,8 @@
+// RUN: moore %s -e foo
+// FAIL
+
+module foo;
+    int x;
+    initial x = bar(42);
+    // CHECK-ERR: error: unknown function `bar`
+endmodule
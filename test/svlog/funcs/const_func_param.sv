@@ -0,0 +1,16 @@
+// RUN: moore %s -e foo -O0
+// A parameter default may be computed by a constant function defined in the
+// same module.
+
+module foo;
+    function automatic int compute(int x);
+        int y = 1;
+        for (int i = 0; i < x; i++)
+            y = y * 2;
+        return y + 1;
+    endfunction
+
+    parameter N = compute(3);
+    logic [N-1:0] x;
+    // CHECK: %x = sig i9 %0
+endmodule
@@ -0,0 +1,15 @@
+// RUN: moore %s -e foo
+// FAIL
+// A constant function cannot read a signal of the enclosing module.
+
+module foo;
+    logic [3:0] s;
+
+    function automatic int compute(int x);
+        return x + s;
+    endfunction
+
+    parameter N = compute(3);
+    logic [N-1:0] x;
+    // CHECK-ERR: error: value is not constant
+endmodule
@@ -0,0 +1,14 @@
+// RUN: moore %s -e foo
+// FAIL
+
+function int bar(int a);
+    const int c = a + 1;
+    c = 2;
+    // CHECK-ERR: error: constant `c` cannot be assigned to
+    return c;
+endfunction
+
+module foo;
+    int x;
+    initial x = bar(1);
+endmodule
@@ -0,0 +1,13 @@
+// RUN: moore %s -e foo
+// FAIL
+// Returning a value by assigning it to the function name is not supported.
+
+module foo;
+    function automatic int compute(int x);
+        compute = x + 1;
+    endfunction
+
+    parameter N = compute(3);
+    logic [N-1:0] x;
+    // CHECK-ERR: error: unsupported: function `compute` used as a value
+endmodule
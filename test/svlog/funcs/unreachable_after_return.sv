@@ -0,0 +1,15 @@
+// RUN: moore %s -e foo
+// Statements after an unconditional `return` are flagged as unreachable.
+
+module foo;
+    function int f(int x);
+        if (x > 0) begin
+            return 1;
+        end
+        return 0;
+        x = 42;
+        // CHECK-ERR: warning: unreachable statement
+    endfunction
+    int y;
+    initial y = f(3);
+endmodule
@@ -0,0 +1,22 @@
+// RUN: moore %s -e foo -O0
+// Continuous assignment delays that depend on a genvar are evaluated for every
+// iteration of the generate loop.
+
+module foo (input logic [3:0] x, output logic [3:0] y);
+    for (genvar i = 1; i < 4; i++) begin
+        assign #(i) y[i] = x[i];
+    end
+    assign y[0] = x[0];
+endmodule
+
+// CHECK: entity @foo (i4$ %x) -> (i4$ %y) {
+// CHECK:     %0 = const time 0s 1e
+// CHECK:     drv i1$ %5, %6, %0
+// CHECK:     %7 = const time 1ns
+// CHECK:     drv i1$ %12, %13, %7
+// CHECK:     %14 = const time 2ns
+// CHECK:     drv i1$ %19, %20, %14
+// CHECK:     %21 = const time 3ns
+// CHECK:     drv i1$ %26, %27, %21
+// CHECK: }
+// CHECK-ERR: warning: assuming `1ns` timescale for conversion from `bit [63:0]` to `time`
@@ -0,0 +1,18 @@
+// RUN: moore %s -e foo -O0
+// Unconditional generate blocks inside a generate region are elaborated, and
+// their declarations are named after the block.
+
+module foo;
+    generate
+        begin : blk
+            logic [5:0] x;
+            bar b();
+        end
+    endgenerate
+    // CHECK: entity @foo () -> () {
+    // CHECK: %blk.x = sig i6 %0
+    // CHECK: inst @bar.param1 () -> ()
+endmodule
+
+module bar;
+endmodule
@@ -0,0 +1,25 @@
+// RUN: moore %s -e foo -O0
+// Case-generate constructs select a block based on a parameter.
+
+module foo;
+    bar #(.K(1)) b1();
+    bar #(.K(5)) b2();
+endmodule
+
+module bar #(parameter int K);
+    case (K)
+        0, 1: begin : small
+            logic [3:0] x;
+        end
+        2: begin : medium
+            logic [7:0] x;
+        end
+        default: begin : large
+            logic [15:0] x;
+        end
+    endcase
+    // CHECK: entity @bar.param1 () -> () {
+    // CHECK: %x = sig i4 %0
+    // CHECK: entity @bar.param2 () -> () {
+    // CHECK: %x = sig i16 %0
+endmodule
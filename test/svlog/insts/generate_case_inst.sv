@@ -0,0 +1,34 @@
+// RUN: moore %s -e foo -O0
+// Case-generate constructs select which submodule is instantiated based on a
+// parameter. Only the matching branch is elaborated.
+
+module foo;
+    bar #(.FAST(0)) b0();
+    bar #(.FAST(1)) b1();
+endmodule
+
+module bar #(parameter int FAST);
+    case (FAST)
+        1: begin : g
+            fast u();
+        end
+        default: begin : g
+            slow u();
+        end
+    endcase
+endmodule
+
+module fast;
+endmodule
+
+module slow;
+endmodule
+
+// CHECK: entity @slow.param2 () -> () {
+// CHECK: entity @bar.param1 () -> () {
+// CHECK-NEXT:     inst @slow.param2 () -> ()
+// CHECK-NEXT: }
+// CHECK: entity @fast.param4 () -> () {
+// CHECK: entity @bar.param3 () -> () {
+// CHECK-NEXT:     inst @fast.param4 () -> ()
+// CHECK-NEXT: }
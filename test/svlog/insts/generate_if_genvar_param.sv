@@ -0,0 +1,23 @@
+// RUN: moore %s -e foo -O0
+// Generate-if conditions may combine a genvar with a parameter.
+
+module foo;
+    bar #(.HAS_RESET(1)) b1();
+    bar #(.HAS_RESET(0)) b2();
+endmodule
+
+module bar #(parameter bit HAS_RESET);
+    for (genvar i = 0; i < 2; i++) begin : g
+        if (i == 0 && HAS_RESET) begin : rst
+            logic [7:0] x;
+        end else begin : norst
+            logic [3:0] x;
+        end
+    end
+    // CHECK: entity @bar.param1 () -> () {
+    // CHECK: %g[0].x = sig i8 %0
+    // CHECK: %g[1].x = sig i4 %1
+    // CHECK: entity @bar.param4 () -> () {
+    // CHECK: %g[0].x = sig i4 %0
+    // CHECK: %g[1].x = sig i4 %1
+endmodule
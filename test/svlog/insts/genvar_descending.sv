@@ -0,0 +1,16 @@
+// RUN: moore %s -e foo -O0
+// Generate-for loops may count down to zero.
+
+module foo #(parameter int N = 4);
+    for (genvar i = N-1; i >= 0; i--) begin : g
+        bar #(.WIDTH(i+1)) u();
+    end
+endmodule
+
+module bar #(parameter int WIDTH);
+    logic [WIDTH-1:0] x;
+    // CHECK: %x = sig i4 %0
+    // CHECK: %x = sig i3 %0
+    // CHECK: %x = sig i2 %0
+    // CHECK: %x = sig i1 %0
+endmodule
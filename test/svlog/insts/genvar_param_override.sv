@@ -0,0 +1,16 @@
+// RUN: moore %s -e foo -O0
+// Parameter overrides that depend on a genvar are folded per iteration.
+
+module foo;
+    for (genvar i = 0; i < 3; i++) begin : g
+        logic [i:0] x;
+        bar #(.WIDTH(i+1)) u(x);
+    end
+endmodule
+
+module bar #(parameter int WIDTH) (input logic [WIDTH-1:0] a);
+    logic [WIDTH-1:0] x;
+    // CHECK: %x = sig i1 %0
+    // CHECK: %x = sig i2 %0
+    // CHECK: %x = sig i3 %0
+endmodule
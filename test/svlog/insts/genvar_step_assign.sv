@@ -0,0 +1,15 @@
+// RUN: moore %s -e foo -O0
+// Generate-for loops may step the genvar with an arbitrary assignment.
+
+module foo;
+    for (genvar i = 0; i < 6; i = i + 2) begin : g
+        bar #(.WIDTH(i+1)) u();
+    end
+endmodule
+
+module bar #(parameter int WIDTH);
+    logic [WIDTH-1:0] x;
+    // CHECK: %x = sig i1 %0
+    // CHECK: %x = sig i3 %0
+    // CHECK: %x = sig i5 %0
+endmodule
@@ -0,0 +1,15 @@
+// RUN: moore %s -e foo
+// Instance arrays may be connected to the signals of a generate block array.
+
+module foo;
+    for (genvar i = 0; i < 4; i++) begin : g
+        logic out;
+    end
+    for (genvar i = 0; i < 4; i++) begin
+        bar u[3:0] (.d(g[i].out));
+    end
+    // CHECK: entity @foo () -> () {
+endmodule
+
+module bar (input logic d);
+endmodule
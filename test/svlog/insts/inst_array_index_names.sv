@@ -0,0 +1,16 @@
+// RUN: moore %s -e top -O0
+
+// The sliced connections of an instance array are named after the index of
+// the array element, not its position.
+module top (input logic [2:0] data);
+    sink u[3:1] (.d(data));
+endmodule
+
+module sink (input logic d);
+endmodule
+
+// CHECK: entity @top (i3$ %data) -> () {
+// CHECK:     inst @sink.param1 (i1$ %u[1].d) -> ()
+// CHECK:     inst @sink.param1 (i1$ %u[2].d) -> ()
+// CHECK:     inst @sink.param1 (i1$ %u[3].d) -> ()
+// CHECK: }
@@ -0,0 +1,24 @@
+// RUN: moore %s -e top -O0
+
+// The 8-bit connections are sliced across the 8 instances, with the
+// right-most instance receiving the least significant bit. The clock is as
+// wide as the port and is broadcast to all instances. The sliced connections
+// are named after the array element and the port.
+module top (input logic clk, input logic [7:0] data, output logic [7:0] out);
+    dff d[7:0] (.clk(clk), .d(data), .q(out));
+endmodule
+
+module dff (input logic clk, input logic d, output logic q);
+    always_ff @(posedge clk) q <= d;
+endmodule
+
+// CHECK: entity @top (i1$ %clk, i8$ %data) -> (i8$ %out) {
+// CHECK:     inst @dff.param1 (i1$ %clk, i1$ %d[0].d) -> (i1$ %d[0].q)
+// CHECK:     inst @dff.param1 (i1$ %clk, i1$ %d[1].d) -> (i1$ %d[1].q)
+// CHECK:     inst @dff.param1 (i1$ %clk, i1$ %d[2].d) -> (i1$ %d[2].q)
+// CHECK:     inst @dff.param1 (i1$ %clk, i1$ %d[3].d) -> (i1$ %d[3].q)
+// CHECK:     inst @dff.param1 (i1$ %clk, i1$ %d[4].d) -> (i1$ %d[4].q)
+// CHECK:     inst @dff.param1 (i1$ %clk, i1$ %d[5].d) -> (i1$ %d[5].q)
+// CHECK:     inst @dff.param1 (i1$ %clk, i1$ %d[6].d) -> (i1$ %d[6].q)
+// CHECK:     inst @dff.param1 (i1$ %clk, i1$ %d[7].d) -> (i1$ %d[7].q)
+// CHECK: }
@@ -0,0 +1,10 @@
+// RUN: moore %s -e foo
+// Unpacked parameter arrays can be overridden with an array pattern.
+
+module foo;
+    bar #(.LUT('{1, 2, 3, 4})) b();
+endmodule
+
+module bar #(parameter int LUT [4] = '{default: 0});
+    int x = LUT[2];
+endmodule
@@ -0,0 +1,11 @@
+// RUN: moore %s -e foo
+// FAIL
+
+module foo;
+    bar #(.LUT('{1, 2, 3})) b();
+    // CHECK-ERR: error: pattern has 3 fields, but type `int $ [4]` requires 4
+endmodule
+
+module bar #(parameter int LUT [4] = '{default: 0});
+    int x = LUT[2];
+endmodule
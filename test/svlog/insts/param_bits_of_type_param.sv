@@ -0,0 +1,15 @@
+// RUN: moore %s -e foo -O0
+// A value parameter defaulting to `$bits` of a preceding type parameter.
+
+module foo;
+    bar #(.T(logic [9:0])) u0();
+    bar u1();
+endmodule
+
+module bar #(parameter type T = logic, parameter int N = $bits(T));
+    logic [N-1:0] x;
+    // CHECK: entity @bar.param1 () -> () {
+    // CHECK: %x = sig i10 %0
+    // CHECK: entity @bar.param2 () -> () {
+    // CHECK: %x = sig i1 %0
+endmodule
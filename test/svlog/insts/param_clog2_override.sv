@@ -0,0 +1,13 @@
+// RUN: moore %s -e foo -O0
+// Parameter defaults that depend on other parameters use the overridden values.
+
+module foo;
+    bar i0();
+    bar #(.DEPTH(1024)) i1();
+endmodule
+
+module bar #(parameter int DEPTH = 256, parameter int AW = $clog2(DEPTH));
+    logic [AW-1:0] x;
+    // CHECK: %x = sig i8 %0
+    // CHECK: %x = sig i10 %0
+endmodule
@@ -0,0 +1,10 @@
+// RUN: moore %s -e foo
+// FAIL
+
+module foo;
+    bar #(.T(42)) i0();
+    // CHECK-ERR: error: parameter `T` requires a type, but `42` was given
+endmodule
+
+module bar #(parameter type T = int);
+endmodule
@@ -0,0 +1,10 @@
+// RUN: moore %s -e foo
+// FAIL
+
+module foo;
+    bar #(.N(logic [3:0])) i0();
+    // CHECK-ERR: error: parameter `N` requires a value, but `logic [3:0]` was given
+endmodule
+
+module bar #(parameter int N = 1);
+endmodule
@@ -0,0 +1,16 @@
+// RUN: moore %s -e top -O0
+// A width overridden at the top propagates through intermediate instances
+// down to the type of a signal in the deepest module.
+
+module top;
+    mid #(.W(12)) m();
+endmodule
+
+module mid #(parameter int W = 2);
+    leaf #(.WIDTH(W)) l();
+endmodule
+
+module leaf #(parameter int WIDTH = 1);
+    logic [WIDTH-1:0] x;
+    // CHECK: %x = sig i12 %0
+endmodule
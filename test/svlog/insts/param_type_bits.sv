@@ -0,0 +1,16 @@
+// RUN: moore %s -e foo -O0
+// Connections and overrides sized by `$bits` of a type parameter.
+
+module foo;
+    bar #(.T(logic [5:0])) u();
+endmodule
+
+module bar #(parameter type T = logic);
+    logic [$bits(T)-1:0] x;
+    baz #(.W($bits(T))) i0(x);
+    // CHECK: %y = sig i6 %0
+endmodule
+
+module baz #(parameter int W) (input logic [W-1:0] a);
+    logic [W-1:0] y;
+endmodule
@@ -0,0 +1,13 @@
+// RUN: moore %s -e foo -O0
+// Type parameter overrides propagate to declarations inside the instance.
+
+module foo;
+    bar #(.T(int)) i0();
+    bar #(.T(logic [4:0])) i1();
+endmodule
+
+module bar #(parameter type T = logic);
+    T x;
+    // CHECK: %x = sig i32 %0
+    // CHECK: %x = sig i5 %0
+endmodule
@@ -0,0 +1,14 @@
+// RUN: moore %s -e foo -O0
+// Type parameter overrides propagate into the fields of struct types.
+
+module foo;
+    bar #(.T(int)) i0();
+    bar #(.T(logic [4:0])) i1();
+endmodule
+
+module bar #(parameter type T = logic);
+    typedef struct packed { T data; logic valid; } pair_t;
+    pair_t x;
+    // CHECK: %x = sig {i32, i1} %2
+    // CHECK: %x = sig {i5, i1} %2
+endmodule
@@ -0,0 +1,15 @@
+// RUN: moore %s -e foo -O0
+// Parameters size both the element width and the depth of a memory.
+
+module foo;
+    bar b0();
+    bar #(.W(4), .DEPTH(32)) b1();
+endmodule
+
+module bar #(parameter int W = 8, parameter int DEPTH = 16);
+    logic [W-1:0] mem [DEPTH];
+    // CHECK: entity @bar.param1 () -> () {
+    // CHECK: %mem = sig [16 x i8] %16
+    // CHECK: entity @bar.param2 () -> () {
+    // CHECK: %mem = sig [32 x i4] %32
+endmodule
@@ -0,0 +1,14 @@
+// RUN: moore %s -e foo
+// Connections that are narrower or wider than their port are flagged.
+
+module foo;
+    logic [3:0] a;
+    logic [15:0] b;
+    bar i0 (.x(a), .y(b));
+    // CHECK-ERR: warning: width mismatch: `a` has 4 bits, but port `x` has 8 bits
+    // CHECK-ERR: warning: width mismatch: `b` has 16 bits, but port `y` has 8 bits
+endmodule
+
+module bar (input logic [7:0] x, output logic [7:0] y);
+    assign y = x;
+endmodule
@@ -0,0 +1,35 @@
+// RUN: moore %s -e adder_tree
+
+// Each level instantiates two copies of itself with a decremented depth,
+// until the generate-if reaches the base case at depth 0. Every instance has
+// its own parameter environment, so each one is emitted as a separate entity.
+module adder_tree #(parameter int DEPTH = 3) (
+    input  logic [(1 << DEPTH)-1:0] in,
+    output logic [DEPTH:0] sum
+);
+    if (DEPTH == 0) begin : base
+        assign sum = in;
+    end else begin : recurse
+        logic [DEPTH-1:0] lo, hi;
+        adder_tree #(DEPTH-1) l (.in(in[(1 << (DEPTH-1))-1:0]), .sum(lo));
+        adder_tree #(DEPTH-1) h (.in(in[(1 << DEPTH)-1:(1 << (DEPTH-1))]), .sum(hi));
+        assign sum = lo + hi;
+    end
+endmodule
+
+// CHECK: entity @adder_tree.param3 (i1$ %in) -> (i1$ %sum) {
+// CHECK: entity @adder_tree.param4 (i1$ %in) -> (i1$ %sum) {
+// CHECK: entity @adder_tree.param2 (i2$ %in) -> (i2$ %sum) {
+// CHECK: entity @adder_tree.param6 (i1$ %in) -> (i1$ %sum) {
+// CHECK: entity @adder_tree.param7 (i1$ %in) -> (i1$ %sum) {
+// CHECK: entity @adder_tree.param5 (i2$ %in) -> (i2$ %sum) {
+// CHECK: entity @adder_tree.param1 (i4$ %in) -> (i3$ %sum) {
+// CHECK: entity @adder_tree.param10 (i1$ %in) -> (i1$ %sum) {
+// CHECK: entity @adder_tree.param11 (i1$ %in) -> (i1$ %sum) {
+// CHECK: entity @adder_tree.param9 (i2$ %in) -> (i2$ %sum) {
+// CHECK: entity @adder_tree.param13 (i1$ %in) -> (i1$ %sum) {
+// CHECK: entity @adder_tree.param14 (i1$ %in) -> (i1$ %sum) {
+// CHECK: entity @adder_tree.param12 (i2$ %in) -> (i2$ %sum) {
+// CHECK: entity @adder_tree.param8 (i4$ %in) -> (i3$ %sum) {
+// CHECK: entity @adder_tree (i8$ %in) -> (i4$ %sum) {
+// CHECK: }
@@ -0,0 +1,9 @@
+// RUN: moore %s -e runaway
+// FAIL
+
+// The recursion lacks a base case and never terminates.
+module runaway #(parameter int N = 0);
+    runaway #(N+1) r();
+endmodule
+
+// CHECK-ERR: error: instantiation of module `runaway` exceeds the maximum hierarchy depth of 256
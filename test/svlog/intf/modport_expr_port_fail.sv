@@ -0,0 +1,18 @@
+// RUN: moore %s -e top
+// FAIL
+
+module top;
+    bus_if b();
+    master m(.bus(b));
+endmodule
+
+module master (bus_if.master bus);
+    logic x;
+    assign x = bus.lsb;
+    // CHECK-ERR: error: unsupported: modport expression port `lsb`
+endmodule
+
+interface bus_if;
+    logic [7:0] data;
+    modport master (input .lsb(data[0]));
+endinterface
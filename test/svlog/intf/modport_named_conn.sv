@@ -0,0 +1,30 @@
+// RUN: moore %s -e top -O0
+// Signals accessed through a modport port resolve to the signals of the
+// interface instance connected to the port.
+
+module top;
+    bus_if b();
+    master m(.bus(b));
+endmodule
+
+module master (bus_if.master bus);
+    logic busy;
+    assign bus.req = 1;
+    assign busy = bus.gnt;
+endmodule
+
+interface bus_if;
+    logic req;
+    logic gnt;
+    modport master (output req, input gnt);
+    modport slave (input req, output gnt);
+endinterface
+
+// CHECK: entity @master.param3 (i1$ %bus.gnt) -> (i1$ %bus.req) {
+// CHECK:     drv i1$ %bus.req, %2, %1
+// CHECK:     drv i1$ %busy, %bus.gnt.prb, %3
+// CHECK: }
+
+// CHECK: entity @top () -> () {
+// CHECK:     inst @master.param3 (i1$ %b.gnt) -> (i1$ %b.req)
+// CHECK: }
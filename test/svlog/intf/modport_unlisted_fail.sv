@@ -0,0 +1,20 @@
+// RUN: moore %s -e top
+// FAIL
+
+module top;
+    bus_if b();
+    master m(.bus(b));
+endmodule
+
+module master (bus_if.master bus);
+    logic x;
+    assign x = bus.debug;
+    // CHECK-ERR: error: `debug` is not accessible through modport `master`
+endmodule
+
+interface bus_if;
+    logic req;
+    logic gnt;
+    logic debug;
+    modport master (output req, input gnt);
+endinterface
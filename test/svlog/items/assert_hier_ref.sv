@@ -0,0 +1,15 @@
+// RUN: moore %s -e top
+// Concurrent assertions may reference signals inside submodule instances. The
+// property expression is not lowered yet, so the assertion is skipped; this
+// makes sure the hierarchical reference does not trip up elaboration.
+
+module top;
+    bit clk;
+    dut u_dut(clk);
+    assert property (@(posedge clk) u_dut.sig == 0);
+    // CHECK-ERR: warning: unsupported: concurrent assertion; ignored
+endmodule
+
+module dut (input bit clk);
+    bit sig;
+endmodule
@@ -0,0 +1,15 @@
+// RUN: moore %s -e foo
+// FAIL
+// Bind directives are parsed, but not elaborated.
+
+module foo;
+    bar u();
+    bind bar baz c();
+    // CHECK-ERR: error: unsupported: bind directive
+endmodule
+
+module bar;
+endmodule
+
+module baz;
+endmodule
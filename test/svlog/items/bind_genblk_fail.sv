@@ -0,0 +1,16 @@
+// RUN: moore %s -e foo
+// FAIL
+
+module foo;
+    for (genvar i = 0; i < 4; i++) begin : g
+        bar u();
+    end
+    bind foo.g[2].u baz c();
+    // CHECK-ERR: error: unsupported: bind directive
+endmodule
+
+module bar;
+endmodule
+
+module baz;
+endmodule
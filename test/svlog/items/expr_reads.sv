@@ -0,0 +1,8 @@
+// RUN: moore %s -e foo -Vreads
+
+module foo (input logic a, input logic b, output logic y);
+    logic [3:0] c;
+    assign y = a & (b | c[2]);
+    // CHECK: Reads of `foo`:
+    // CHECK:   assign y = a & (b | c[2]): a, b, c
+endmodule
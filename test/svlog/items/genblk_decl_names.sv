@@ -0,0 +1,11 @@
+// RUN: moore %s -e foo -O0
+// Declarations in a generate loop are named after their loop iteration.
+
+module foo;
+    for (genvar i = 0; i < 2; i++) begin : g
+        logic [7:0] bus;
+    end
+    // CHECK: entity @foo () -> () {
+    // CHECK: %g[0].bus = sig i8 %0
+    // CHECK: %g[1].bus = sig i8 %1
+endmodule
@@ -0,0 +1,14 @@
+// RUN: moore %s -e foo
+// Hierarchical references may select a declaration in one iteration of a
+// generate loop.
+
+module foo (input logic x);
+    for (genvar i = 0; i < 2; i++) begin : g
+        logic out;
+    end
+    for (genvar i = 0; i < 2; i++) begin
+        assign g[i].out = x;
+    end
+    // CHECK: entity @foo (i1$ %x) -> () {
+    // CHECK: %x.prb = prb i1$ %x
+endmodule
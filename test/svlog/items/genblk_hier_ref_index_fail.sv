@@ -0,0 +1,11 @@
+// RUN: moore %s -e foo
+// FAIL
+// Generate block indices that do not fit into a machine integer are rejected.
+
+module foo (input logic x);
+    for (genvar i = 0; i < 2; i++) begin : g
+        logic out;
+    end
+    assign g[80'hffffffffffffffffffff].out = x;
+    // CHECK-ERR: error: generate block index `1208925819614629174706175` is too large
+endmodule
@@ -0,0 +1,11 @@
+// RUN: moore %s -e foo
+// FAIL
+// Generate blocks can only be referenced after the loop that creates them.
+
+module foo (input logic x);
+    assign g[0].out = x;
+    for (genvar i = 0; i < 2; i++) begin : g
+        logic out;
+    end
+    // CHECK-ERR: error: generate block `g[0]` has not been elaborated
+endmodule
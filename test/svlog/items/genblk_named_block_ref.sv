@@ -0,0 +1,13 @@
+// RUN: moore %s -e foo -O0
+// Hierarchical references may select a declaration in a named generate block.
+
+module foo (input logic x);
+    generate
+        begin : blk
+            logic y;
+        end
+    endgenerate
+    assign blk.y = x;
+    // CHECK: entity @foo (i1$ %x) -> () {
+    // CHECK: %blk.y = sig i1 %0
+endmodule
@@ -0,0 +1,13 @@
+// RUN: moore %s -e foo -O0
+
+module foo;
+    import pkg::word_t;
+    import pkg::WIDTH;
+    word_t a = WIDTH;
+endmodule
+
+package pkg;
+    localparam int WIDTH = 16;
+    localparam int DEPTH = 4;
+    typedef logic [WIDTH-1:0] word_t;
+endpackage
@@ -0,0 +1,16 @@
+// RUN: moore %s -e foo -O0
+// Names from a wildcard import are visible, but local declarations shadow them.
+
+package pkg;
+    localparam int A = 42;
+    localparam int B = 1;
+endpackage
+
+module foo;
+    import pkg::*;
+    localparam int B = 7;
+    int a = A;
+    int b = B;
+    // CHECK: %0 = const i32 42
+    // CHECK: %1 = const i32 7
+endmodule
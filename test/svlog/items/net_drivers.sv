@@ -0,0 +1,13 @@
+// RUN: moore %s -e foo -Vdrivers
+
+module foo (input logic a, input logic b);
+    wire x;
+    assign x = a;
+    assign x = b;
+    logic y;
+    always_comb y = a;
+    // CHECK: Drivers of `foo`:
+    // CHECK:   x: assign x = a
+    // CHECK:   x: assign x = b
+    // CHECK:   y: `always_comb` procedure
+endmodule
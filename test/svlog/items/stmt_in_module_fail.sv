@@ -0,0 +1,8 @@
+// RUN: moore %s -e foo
+// FAIL
+
+module foo;
+    logic x;
+    x = 1;
+    // CHECK-ERR: error: procedural statement outside of a process
+endmodule
@@ -0,0 +1,16 @@
+// RUN: moore %s -e foo -e bar
+
+// A module without any items.
+module foo;
+endmodule
+
+// CHECK: entity @foo () -> () {
+// CHECK: }
+
+// A module with only parameters.
+module bar #(parameter int A = 1) ();
+    localparam int B = A + 1;
+endmodule
+
+// CHECK: entity @bar () -> () {
+// CHECK: }
@@ -0,0 +1,17 @@
+// RUN: moore %s -e foo -O0
+// String-typed parameters with string literal defaults.
+
+module foo;
+    bar i0();
+    bar #(.NAME("ab")) i1();
+endmodule
+
+module bar #(parameter string NAME = "foo");
+    baz #(NAME) i0();
+    // CHECK: %0 = const i32 6713199
+    // CHECK: %0 = const i32 24930
+endmodule
+
+module baz #(parameter int X);
+    int x = X;
+endmodule
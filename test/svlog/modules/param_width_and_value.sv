@@ -0,0 +1,12 @@
+// RUN: moore %s -e foo -O0
+// A parameter may be used both as a width and as a value.
+
+module foo;
+    parameter W = 8;
+    logic [W-1:0] x;
+    int y;
+    assign y = W;
+    // CHECK: %x = sig i8 %0
+    // CHECK: %3 = const i32 8
+    // CHECK-NEXT: drv i32$ %y, %3, %2
+endmodule
@@ -0,0 +1,9 @@
+// RUN: moore %s -e foo -Vparams
+
+module foo #(parameter type T = logic [7:0], parameter int N = 4);
+    localparam int M = N * 2;
+    // CHECK: Parameters of `foo`:
+    // CHECK:   type T = logic [7:0]
+    // CHECK:   value N = 4
+    // CHECK: entity @foo () -> () {
+endmodule
@@ -0,0 +1,19 @@
+// RUN: moore %s -e top
+// Explicit ports are connected by their external name and map onto the
+// internal signal, with the direction given in the port list.
+
+module top;
+    logic [7:0] x;
+    logic y;
+    foo f(.out(x), .in(y));
+endmodule
+
+module foo (input .in(a), output .out(b));
+    logic a;
+    logic [7:0] b;
+    assign b = {8{a}};
+endmodule
+
+// CHECK: entity @foo.param1 (i1$ %a) -> (i8$ %b) {
+// CHECK: entity @top () -> () {
+// CHECK:     inst @foo.param1 (i1$ %y) -> (i8$ %x)
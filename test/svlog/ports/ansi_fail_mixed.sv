@@ -0,0 +1,13 @@
+// RUN: moore %s -e foo -e bar
+// FAIL
+
+module foo (input logic a, {b, c});
+    logic b, c;
+endmodule
+
+module bar (a, input logic b);
+    input a;
+endmodule
+
+// CHECK-ERR: error: non-ANSI port in ANSI port list
+// CHECK-ERR: error: ANSI port in non-ANSI port list
@@ -0,0 +1,10 @@
+// RUN: moore %s -e foo -Vports
+
+module foo (inout wire [7:0] x, input logic a);
+    // CHECK: Ports of `foo`:
+    // CHECK:   internal:
+    // CHECK:     0: inout wire logic [7:0] x
+    // CHECK:     1: input wire logic a
+    // CHECK: entity @foo (i1$ %a) -> (i8$ %x) {
+    // CHECK-ERR: warning: unsupported: bidirectional port `x`; lowered as output
+endmodule
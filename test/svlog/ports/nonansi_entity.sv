@@ -0,0 +1,11 @@
+// RUN: moore %s -e foo
+// The merged non-ANSI port declarations determine the entity signature.
+
+module foo(a, b, y);
+    input [3:0] a;
+    input [7:0] b;
+    bit [7:0] b;
+    output [1:0] y;
+    logic [1:0] y;
+    // CHECK: entity @foo (i4$ %a, i8$ %b) -> (i2$ %y) {
+endmodule
@@ -0,0 +1,11 @@
+// RUN: moore %s -e foo
+// FAIL
+module foo(a, b);
+    input [7:0] a;
+    wire [3:0] a;
+    // CHECK-ERR: error: port `a` has contradicting packed dimensions
+
+    input b [2];
+    logic b [4];
+    // CHECK-ERR: error: port `b` has contradicting unpacked dimensions
+endmodule
@@ -0,0 +1,23 @@
+// RUN: moore %s -e foo -Vports
+// Dimensions of the net or variable declaration carry over to the port.
+
+module foo(a, b, y, z);
+    input a;
+    wire [7:0] a;
+    // CHECK: 0: input wire logic [7:0] a
+
+    input [3:0] b;
+    logic [3:0] b;
+    // CHECK: 1: input var logic [3:0] b
+
+    output y;
+    logic [1:0] y;
+    // CHECK: 2: output var logic [1:0] y
+
+    // Dimensions agree if their bounds evaluate to the same range.
+    output [8-1:0] z;
+    logic [7:0] z;
+    // CHECK: 3: output var logic [7:0] z
+
+    // CHECK: entity @foo (i8$ %a, i4$ %b) -> (i2$ %y, i8$ %z) {
+endmodule
@@ -0,0 +1,12 @@
+// RUN: moore %s -e foo
+// Dimensions given as a size agree if the port and its net or variable
+// declaration both use them.
+
+module foo(a, b);
+    parameter int N = 4;
+    input [N] a;
+    wire [N] a;
+    input b [N];
+    logic b [N];
+    // CHECK: entity @foo (i4$ %a, [4 x i1]$ %b) -> () {
+endmodule
@@ -0,0 +1,18 @@
+// RUN: moore %s -e top
+
+// Positional connections follow the order of the port list, not the order of
+// the declarations in the module body.
+module top;
+    bit [7:0] q;
+    bit [3:0] p;
+    bar b(q, p);
+endmodule
+
+module bar (y, a);
+    input [3:0] a;
+    output [7:0] y;
+endmodule
+
+// CHECK: entity @bar.param1 (i4$ %a) -> (i8$ %y) {
+// CHECK: entity @top () -> () {
+// CHECK:     inst @bar.param1 (i4$ %p) -> (i8$ %q)
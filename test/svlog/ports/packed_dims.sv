@@ -0,0 +1,10 @@
+// RUN: moore %s -e foo -Vports
+
+module foo (input logic [7:0][3:0] x, input logic [4] y, output logic [1:0][4] z);
+    // CHECK: Ports of `foo`:
+    // CHECK:   internal:
+    // CHECK:     0: input wire logic [7:0][3:0] x
+    // CHECK:     1: input wire logic [3:0] y
+    // CHECK:     2: output var logic [1:0][3:0] z
+    // CHECK: entity @foo ([8 x i4]$ %x, i4$ %y) -> ([2 x i4]$ %z) {
+endmodule
@@ -0,0 +1,16 @@
+// RUN: moore %s -e foo -Vports
+// The packed dimension applies to the elements of an unpacked array port.
+
+module foo (
+    input logic [7:0] mem [0:255],
+    input logic [7:0] addr,
+    output logic [7:0] data
+);
+    assign data = mem[addr];
+    // CHECK: Ports of `foo`:
+    // CHECK:   internal:
+    // CHECK:     0: input wire logic [7:0] $ [0:255] mem
+    // CHECK:     1: input wire logic [7:0] addr
+    // CHECK:     2: output var logic [7:0] data
+    // CHECK: entity @foo ([256 x i8]$ %mem, i8$ %addr) -> (i8$ %data) {
+endmodule
@@ -0,0 +1,9 @@
+// RUN: moore %s -e foo -Vports
+
+module foo (
+    input logic [7:0] a,
+    output logic z
+);
+    // CHECK: Ports of `foo`:
+    // CHECK:   widths: 8 input, 1 output, 0 inout
+endmodule
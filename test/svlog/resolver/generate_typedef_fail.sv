@@ -0,0 +1,12 @@
+// RUN: moore %s -e foo
+// FAIL
+// Typedefs declared in a generate block are only visible within that block.
+
+module foo;
+    if (1) begin : g
+        typedef logic [3:0] nibble_t;
+        nibble_t x;
+    end
+    nibble_t y;
+    // CHECK-ERR: error: `nibble_t` not found
+endmodule
@@ -0,0 +1,7 @@
+// RUN: moore %s -e foo
+// Names qualified with the built-in `std` package resolve.
+
+module foo;
+    int ok;
+    initial ok = std::randomize();
+endmodule
@@ -0,0 +1,11 @@
+// RUN: moore %s -e foo
+// A user-provided `std` package replaces the built-in one.
+
+package std;
+    localparam int WIDTH = 4;
+endpackage
+
+module foo;
+    logic [std::WIDTH-1:0] x;
+    // CHECK: %x = sig i4 %0
+endmodule
@@ -0,0 +1,13 @@
+// RUN: moore %s -e foo
+
+module foo;
+    logic a, b, x, y;
+    always_comb begin
+        y = 0;
+        if (a)
+            x = b;
+        else
+            y = b;
+    end
+    // CHECK-ERR: warning: latch inferred for variable `x`
+endmodule
@@ -0,0 +1,22 @@
+// RUN: moore %s -e foo
+// Loops that are known to execute and case statements whose labels cover the
+// entire selector assign their targets on all paths.
+
+module foo;
+    logic [1:0] s;
+    logic [3:0] x;
+    logic y, z, a;
+    always_comb begin
+        for (int i = 0; i < 4; i++)
+            x[i] = a;
+        case (s)
+            2'd0: y = 0;
+            2'd1: y = 1;
+            2'd2: y = a;
+            2'd3: y = !a;
+        endcase
+        if (a)
+            z = 1;
+    end
+    // CHECK-ERR: warning: latch inferred for variable `z`
+endmodule
@@ -0,0 +1,9 @@
+// RUN: moore %s -e foo
+// FAIL
+
+module foo;
+    logic clk, a, q;
+    always_ff @(posedge clk) q <= a;
+    always_ff @(a) q <= a;
+    // CHECK-ERR: error: `always_ff` cannot be sensitive to level-triggered event `a`
+endmodule
@@ -0,0 +1,13 @@
+// RUN: moore %s -e foo
+// FAIL
+// Pattern-matching case statements are parsed, but rejected during lowering.
+module foo;
+    int x, y;
+    always_comb begin
+        case (x) matches
+            1: y = 2;
+            default: y = 0;
+        endcase
+    end
+    // CHECK-ERR: error: unsupported: pattern matching `case ... matches`
+endmodule
@@ -0,0 +1,15 @@
+// RUN: moore %s -e foo
+// FAIL
+// Tagged union patterns with bound pattern variables are not yet supported.
+module foo;
+    typedef union tagged { int Valid; bit [3:0] Other; } maybe_t;
+    maybe_t x;
+    int y;
+    always_comb begin
+        case (x) matches
+            tagged Valid .v: y = v;
+            default: y = 0;
+        endcase
+    end
+    // CHECK-ERR: error: Tagged union expressions not implemented
+endmodule
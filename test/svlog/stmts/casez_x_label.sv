@@ -0,0 +1,12 @@
+// RUN: moore %s -e foo
+
+module foo (input logic [3:0] a, output logic [1:0] z);
+    always_comb begin
+        casez (a)
+            4'b1??0: z = 0;
+            4'b01x0: z = 1;
+            // CHECK-ERR: warning: `x` bits in `casez` label are not don't-care
+            default: z = 2;
+        endcase
+    end
+endmodule
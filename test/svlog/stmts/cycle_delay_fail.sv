@@ -0,0 +1,9 @@
+// RUN: moore %s -e foo
+// FAIL
+
+module foo (input logic clk, output logic z);
+    initial begin
+        ##2 z = 1;
+        // CHECK-ERR: error: unsupported: cycle delay outside of a clocking block
+    end
+endmodule
@@ -0,0 +1,14 @@
+// RUN: moore %s -e foo
+// A `disable` of an enclosing named block skips the rest of that block.
+
+module foo (input logic a, input logic b, output logic z);
+    initial begin : outer
+        z = 0;
+        begin : inner
+            if (a) disable inner;
+            if (b) disable outer;
+            z = 1;
+        end
+        z = 2;
+    end
+endmodule
@@ -0,0 +1,12 @@
+// RUN: moore %s -e foo
+// A `disable fork` after a single-process `fork ... join_any`.
+
+module foo (input logic a, output logic z);
+    initial begin
+        fork
+            wait (a) z = 0;
+        join_any
+        disable fork;
+        z = 1;
+    end
+endmodule
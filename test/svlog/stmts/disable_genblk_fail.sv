@@ -0,0 +1,16 @@
+// RUN: moore %s -e foo
+// FAIL
+// Disable statements may target generate block instances. They are accepted
+// by the parser, but only enclosing named blocks can be disabled.
+
+module foo;
+    for (genvar i = 0; i < 4; i++) begin : g
+        initial begin : blk
+        end
+    end
+
+    initial begin
+        disable g[0];
+    end
+    // CHECK-ERR: error: unsupported: disable of a hierarchical name
+endmodule
@@ -0,0 +1,13 @@
+// RUN: moore %s -e foo
+// FAIL
+// Only enclosing named blocks can be disabled, not tasks.
+
+module foo;
+    task t;
+    endtask
+
+    initial begin
+        disable t;
+    end
+    // CHECK-ERR: error: unsupported: disable of `t`
+endmodule
@@ -0,0 +1,10 @@
+// RUN: moore %s -e foo
+// FAIL
+
+module foo (input logic a, output logic z);
+    initial begin
+        force z = a;
+        // CHECK-ERR: error: unsupported: force and release statements
+        release z;
+    end
+endmodule
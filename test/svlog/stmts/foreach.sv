@@ -0,0 +1,13 @@
+// RUN: moore %s -e foo
+// Multi-dimensional foreach loops iterate over each named dimension, from the
+// left to the right bound. Skipped dimensions are not iterated over.
+
+module foo;
+    int a [2][0:3];
+    initial begin
+        foreach (a[i, j])
+            a[i][j] = i + j;
+        foreach (a[, j])
+            a[0][j] = j;
+    end
+endmodule
@@ -0,0 +1,12 @@
+// RUN: moore %s -e foo
+// FAIL
+
+module foo (input logic a, input logic b);
+    initial begin
+        fork
+            wait (a);
+            wait (b);
+        join
+    end
+    // CHECK-ERR: error: unsupported: fork with multiple concurrent processes
+endmodule
@@ -0,0 +1,11 @@
+// RUN: moore %s -e foo
+// A `wait` inside a single-process `fork`.
+
+module foo (input logic a, output logic z);
+    initial begin
+        fork
+            wait (a);
+        join
+        z = 1;
+    end
+endmodule
@@ -0,0 +1,10 @@
+// RUN: moore %s -e foo
+// FAIL
+
+module foo (input logic a, output logic z);
+    initial begin
+        assign z = a;
+        // CHECK-ERR: error: unsupported: procedural continuous assignment
+        deassign z;
+    end
+endmodule
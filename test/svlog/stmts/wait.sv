@@ -0,0 +1,13 @@
+// RUN: moore %s -e foo -O0
+// A `wait` statement checks its condition and otherwise waits for the signals
+// it reads to change before executing the guarded statement.
+
+module foo (input logic a, output logic z);
+    initial begin
+        wait (a) z = 1;
+    end
+endmodule
+
+// CHECK: wait_check:
+// CHECK: wait_trigger:
+// CHECK: wait_done:
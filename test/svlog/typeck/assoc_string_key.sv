@@ -0,0 +1,21 @@
+// RUN: moore %s -e foo -Vtypes
+// FAIL
+
+module foo;
+    int m [string];
+    string k;
+    int x, y;
+
+    initial begin
+        x = m["key"];
+        // CHECK: 10: type_context("key") = string
+        // CHECK: 10: cast_type("key") = string
+        // CHECK: 10: type(m["key"]) = int
+        y = m[k];
+        // CHECK: 13: type(k) = string
+        // CHECK: 13: type(m[k]) = int
+    end
+
+    // Associative arrays cannot be mapped to hardware yet.
+    // CHECK-ERR: error: unsupported: `m` of type `int $ [string]` has no fixed size
+endmodule
@@ -0,0 +1,9 @@
+// RUN: moore %s -e foo
+// FAIL
+
+module foo;
+    int m [string];
+    int x;
+    initial x = m[42];
+    // CHECK-ERR: error: key `42` of type `int` cannot index into `int $ [string]`
+endmodule
@@ -0,0 +1,15 @@
+// RUN: moore %s -e foo
+
+module foo;
+    typedef enum logic [2:0] { A, B, C } my_enum_t;
+    my_enum_t [3:0] arr;
+    my_enum_t e;
+
+    initial begin
+        arr[0] = 3'd1;
+        arr[1] = 3'd7;
+        // CHECK-ERR: warning: value `7` is not a variant of enum `my_enum_t`
+        e = 3'd5;
+        // CHECK-ERR: warning: value `5` is not a variant of enum `my_enum_t`
+    end
+endmodule
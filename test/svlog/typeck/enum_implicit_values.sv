@@ -0,0 +1,13 @@
+// RUN: moore %s -e foo -O0
+// Enum variants without an explicit value are one greater than the previous
+// variant, starting at zero.
+
+module foo;
+    typedef enum { A, B = 5, C, D = 2, E } e_t;
+    logic [A:0] a;
+    logic [C-1:0] c;
+    logic [E:0] e;
+    // CHECK: %a = sig i1 %0
+    // CHECK: %c = sig i6 %1
+    // CHECK: %e = sig i4 %2
+endmodule
@@ -0,0 +1,11 @@
+// RUN: moore %s -e foo -O0
+// Type member selection names the type of a nested struct member.
+
+module foo;
+    typedef struct packed { logic [4:0] data; logic valid; } inner_t;
+    typedef struct packed { inner_t inner; logic [2:0] tag; } outer_t;
+    outer_t.inner x;
+    outer_t.inner.data y;
+    // CHECK: %x = sig {i5, i1} %2
+    // CHECK: %y = sig i5 %3
+endmodule